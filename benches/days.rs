@@ -0,0 +1,40 @@
+// Benchmarks every registered day against its default input.
+//
+// Run with `cargo bench`, optionally followed by the days to include, e.g.
+// `cargo bench -- 1 3`. Set BENCH_RUNS to change the number of runs per
+// stage (10 by default).
+
+extern crate adventofcode2015;
+
+use std::env;
+use adventofcode2015::bench::{self, format_duration};
+use adventofcode2015::input::{self, Source};
+use adventofcode2015::registry;
+
+fn main() {
+  let runs = env::var("BENCH_RUNS").ok()
+  .and_then(|runs| runs.parse().ok())
+  .unwrap_or(10);
+
+  // cargo passes its own flags, such as --bench, which are not day filters
+  let filter: Vec<usize> = env::args().skip(1)
+  .filter_map(|arg| arg.parse().ok())
+  .collect();
+
+  for (day, entry) in registry::registry() {
+    if !filter.is_empty() && !filter.contains(&day) {
+      continue;
+    }
+
+    let raw = input::load(day, &Source::Default).expect("Missing default input");
+    let timings = bench::bench_day(&entry, &raw, &[1, 2], runs).expect("Invalid default input");
+
+    for (stage, stats) in timings {
+      println!("day{}/{:<6} time: [{} {} {}]",
+        day, stage.to_string(),
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.max));
+    }
+  }
+}
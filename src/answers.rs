@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+use crypto::digest::Digest;
+use crypto::md5::Md5;
+use error::{Error, Result};
+
+/// Hashes puzzle input so answers can be tied to the input they came from.
+/// Surrounding whitespace is ignored.
+pub fn input_hash(raw: &str) -> String {
+  let mut hasher = Md5::new();
+  hasher.input_str(raw.trim());
+  hasher.result_str()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+  pub day: usize,
+  pub part: usize,
+  pub input: String,
+}
+
+impl Key {
+  pub fn new(day: usize, part: usize, raw: &str) -> Self {
+    Key { day, part, input: input_hash(raw) }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+  Pass,
+  Fail { expected: i64 },
+  New,
+}
+
+/// Known answers, stored as a list of `[[answer]]` tables in a TOML file.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+  answers: BTreeMap<Key, i64>,
+}
+
+#[derive(Default)]
+struct Table {
+  line: usize,
+  day: Option<usize>,
+  part: Option<usize>,
+  input: Option<String>,
+  value: Option<i64>,
+}
+
+impl Table {
+  fn into_entry(self) -> Result<(Key, i64)> {
+    let line = self.line;
+    let missing = |field| Error::Answers { line, reason: format!("answer is missing `{}`", field) };
+
+    let key = Key {
+      day: self.day.ok_or_else(|| missing("day"))?,
+      part: self.part.ok_or_else(|| missing("part"))?,
+      input: self.input.ok_or_else(|| missing("input"))?,
+    };
+    Ok((key, self.value.ok_or_else(|| missing("value"))?))
+  }
+}
+
+impl AnswerStore {
+  pub fn new() -> Self {
+    AnswerStore { answers: BTreeMap::new() }
+  }
+
+  pub fn parse(raw: &str) -> Result<Self> {
+    let mut store = AnswerStore::new();
+    let mut table: Option<Table> = None;
+
+    for (l, line) in raw.lines().enumerate() {
+      let line_no = l + 1;
+      let invalid = |reason: &str| Error::Answers { line: line_no, reason: reason.to_string() };
+
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      if line == "[[answer]]" {
+        if let Some(done) = table.take() {
+          store.add(done)?;
+        }
+        table = Some(Table { line: line_no, ..Table::default() });
+        continue;
+      }
+
+      let current = table.as_mut().ok_or_else(|| invalid("expected `[[answer]]`"))?;
+      let mut pair = line.splitn(2, '=');
+      let name = pair.next().unwrap_or("").trim();
+      let value = pair.next().ok_or_else(|| invalid("expected `key = value`"))?.trim();
+
+      let number = || value.parse::<i64>().map_err(|_| invalid("expected a number"));
+      match name {
+        "day" => current.day = Some(value.parse::<usize>().map_err(|_| invalid("expected a day number"))?),
+        "part" => current.part = Some(match value {
+          "1" => 1,
+          "2" => 2,
+          _ => return Err(invalid("expected part 1 or 2")),
+        }),
+        "value" => current.value = Some(number()?),
+        "input" => {
+          if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+            return Err(invalid("expected a quoted string"));
+          }
+          current.input = Some(value[1..value.len() - 1].to_string());
+        },
+        _ => return Err(invalid("expected one of `day`, `part`, `input` or `value`")),
+      }
+    }
+
+    if let Some(done) = table {
+      store.add(done)?;
+    }
+    Ok(store)
+  }
+
+  // Adds a parsed table, refusing a second answer to the same question.
+  fn add(&mut self, table: Table) -> Result<()> {
+    let line = table.line;
+    let (key, value) = table.into_entry()?;
+    if self.answers.contains_key(&key) {
+      let reason = format!("day {}, part {} is already answered for this input", key.day, key.part);
+      return Err(Error::Answers { line, reason });
+    }
+    self.insert(key, value);
+    Ok(())
+  }
+
+  /// Loads the store at `path`, treating a missing file as an empty store.
+  pub fn load(path: &Path) -> Result<Self> {
+    let mut raw = String::new();
+    match File::open(path) {
+      Ok(mut file) => file.read_to_string(&mut raw)?,
+      Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(AnswerStore::new()),
+      Err(err) => return Err(err.into()),
+    };
+    AnswerStore::parse(&raw)
+  }
+
+  pub fn save(&self, path: &Path) -> Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(self.to_string().as_bytes())?;
+    Ok(())
+  }
+
+  pub fn get(&self, key: &Key) -> Option<i64> {
+    self.answers.get(key).cloned()
+  }
+
+  pub fn insert(&mut self, key: Key, value: i64) {
+    self.answers.insert(key, value);
+  }
+
+  pub fn check(&self, key: &Key, value: i64) -> Verdict {
+    match self.get(key) {
+      Some(expected) if expected == value => Verdict::Pass,
+      Some(expected) => Verdict::Fail { expected },
+      None => Verdict::New,
+    }
+  }
+}
+
+impl fmt::Display for AnswerStore {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(f, "# Known answers, keyed by day, part and the MD5 of the puzzle input.")?;
+    writeln!(f, "# Maintained by `adventofcode2015 verify --record`.")?;
+
+    for (key, value) in &self.answers {
+      writeln!(f)?;
+      writeln!(f, "[[answer]]")?;
+      writeln!(f, "day = {}", key.day)?;
+      writeln!(f, "part = {}", key.part)?;
+      writeln!(f, "input = \"{}\"", key.input)?;
+      writeln!(f, "value = {}", value)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn input_hash_ignores_surrounding_whitespace() {
+    assert_eq!(input_hash("ckczppom"), input_hash("ckczppom\n"));
+    assert_ne!(input_hash("ckczppom"), input_hash("abcdef"));
+  }
+
+  #[test]
+  fn store_round_trips() {
+    let mut store = AnswerStore::new();
+    store.insert(Key::new(1, 1, "(())"), 0);
+    store.insert(Key::new(1, 2, "(())"), -1);
+    store.insert(Key::new(4, 1, "abcdef"), 609043);
+
+    assert_eq!(store, AnswerStore::parse(&store.to_string()).unwrap());
+  }
+
+  #[test]
+  fn check_works() {
+    let mut store = AnswerStore::new();
+    store.insert(Key::new(5, 1, "aaa"), 1);
+
+    assert_eq!(Verdict::Pass, store.check(&Key::new(5, 1, "aaa"), 1));
+    assert_eq!(Verdict::Fail { expected: 1 }, store.check(&Key::new(5, 1, "aaa"), 0));
+    assert_eq!(Verdict::New, store.check(&Key::new(5, 2, "aaa"), 0));
+    assert_eq!(Verdict::New, store.check(&Key::new(5, 1, "bbb"), 1));
+  }
+
+  #[test]
+  fn parse_reports_bad_lines() {
+    let line = |raw| match AnswerStore::parse(raw) {
+      Err(Error::Answers { line, .. }) => line,
+      other => panic!("Expected an answers error, got {:?}", other),
+    };
+
+    assert_eq!(1, line("day = 1"));
+    assert_eq!(3, line("[[answer]]\nday = 1\npart = two"));
+    assert_eq!(1, line("[[answer]]\nday = 1\npart = 2\nvalue = 3"));
+    assert_eq!(2, line("[[answer]]\nweek = 1"));
+    assert_eq!(2, line("[[answer]]\nday = -1"));
+    assert_eq!(3, line("[[answer]]\nday = 1\npart = 3"));
+    assert_eq!(3, line("[[answer]]\nday = 1\npart = -2"));
+
+    let entry = "[[answer]]\nday = 1\npart = 2\ninput = \"abc\"\nvalue = 3\n";
+    assert_eq!(6, line(&entry.repeat(2)));
+  }
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::hint::black_box;
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use error::{Error, Result};
+use registry::Entry;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+  pub runs: usize,
+  pub min: Duration,
+  pub median: Duration,
+  pub max: Duration,
+}
+
+impl Stats {
+  pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+    assert!(!samples.is_empty(), "Cannot summarize zero samples");
+    samples.sort();
+
+    let runs = samples.len();
+    let mid = runs / 2;
+    let median = if runs % 2 == 1 {
+      samples[mid]
+    } else {
+      (samples[mid - 1] + samples[mid]) / 2
+    };
+
+    Stats { runs, min: samples[0], median, max: samples[runs - 1] }
+  }
+}
+
+/// Calls `f` `runs` times, timing each call separately.
+pub fn measure<F: FnMut()>(runs: usize, mut f: F) -> Stats {
+  let samples = (0..runs.max(1))
+  .map(|_| {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+  })
+  .collect();
+
+  Stats::from_samples(samples)
+}
+
+/// The stages of a day that get timed: parsing, then each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+  Parse,
+  Part(usize),
+}
+
+impl fmt::Display for Stage {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Stage::Parse => write!(f, "parse"),
+      Stage::Part(part) => write!(f, "part{}", part),
+    }
+  }
+}
+
+impl Stage {
+  fn from_name(name: &str) -> Option<Self> {
+    if name == "parse" {
+      return Some(Stage::Parse);
+    }
+    name.strip_prefix("part")
+    .and_then(|part| part.parse().ok())
+    .map(Stage::Part)
+  }
+}
+
+pub fn bench_day(entry: &Entry, raw: &str, parts: &[usize], runs: usize) -> Result<Vec<(Stage, Stats)>> {
+  // parse once up front so a bad input is reported rather than timed
+  let input = entry.solver.prepare(raw)?;
+
+  let mut timings = vec![(Stage::Parse, measure(runs, || { black_box(entry.solver.prepare(raw)).ok(); }))];
+  for &part in parts {
+    // likewise a part that fails would only time how quickly it gives up
+    entry.solver.solve(&*input, part)?;
+    let stats = measure(runs, || { black_box(entry.solver.solve(&*input, part)).ok(); });
+    timings.push((Stage::Part(part), stats));
+  }
+  Ok(timings)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+  let nanos = duration.as_nanos() as f64;
+  if nanos < 1e3 {
+    format!("{:.0} ns", nanos)
+  } else if nanos < 1e6 {
+    format!("{:.2} µs", nanos / 1e3)
+  } else if nanos < 1e9 {
+    format!("{:.2} ms", nanos / 1e6)
+  } else {
+    format!("{:.2} s", nanos / 1e9)
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+  Improved(f64),
+  Unchanged(f64),
+  Regressed(f64),
+}
+
+/// Median timings saved from an earlier run, one `day stage nanoseconds`
+/// line per stage.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+  medians: BTreeMap<(usize, Stage), Duration>,
+}
+
+impl Baseline {
+  pub fn new() -> Self {
+    Baseline { medians: BTreeMap::new() }
+  }
+
+  pub fn parse(raw: &str) -> Result<Self> {
+    let mut baseline = Baseline::new();
+
+    for (l, line) in raw.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let fields: Vec<&str> = line.split_whitespace().collect();
+      let entry = match fields.as_slice() {
+        [day, stage, nanos] => day.parse().ok()
+        .and_then(|day| Stage::from_name(stage).map(|stage| (day, stage)))
+        .and_then(|key| nanos.parse().ok().map(|nanos| (key, Duration::from_nanos(nanos)))),
+        _ => None,
+      };
+
+      let (key, median) = entry.ok_or_else(|| Error::Baseline {
+        line: l + 1,
+        reason: "expected `<day> <stage> <nanoseconds>`".to_string(),
+      })?;
+      baseline.medians.insert(key, median);
+    }
+    Ok(baseline)
+  }
+
+  /// Loads the baseline at `path`, treating a missing file as empty.
+  pub fn load(path: &Path) -> Result<Self> {
+    let mut raw = String::new();
+    match File::open(path) {
+      Ok(mut file) => file.read_to_string(&mut raw)?,
+      Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(Baseline::new()),
+      Err(err) => return Err(err.into()),
+    };
+    Baseline::parse(&raw)
+  }
+
+  pub fn save(&self, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    let mut file = File::create(path)?;
+    file.write_all(self.to_string().as_bytes())?;
+    Ok(())
+  }
+
+  pub fn record(&mut self, day: usize, stage: Stage, stats: &Stats) {
+    self.medians.insert((day, stage), stats.median);
+  }
+
+  /// Compares a median against the baseline, where `threshold` is the
+  /// fraction by which it has to move to count as a change.
+  pub fn compare(&self, day: usize, stage: Stage, stats: &Stats, threshold: f64) -> Option<Change> {
+    let base = self.medians.get(&(day, stage))?.as_nanos() as f64;
+    if base == 0.0 {
+      return None;
+    }
+
+    let delta = (stats.median.as_nanos() as f64 - base) / base;
+    Some(if delta > threshold {
+      Change::Regressed(delta)
+    } else if delta < -threshold {
+      Change::Improved(delta)
+    } else {
+      Change::Unchanged(delta)
+    })
+  }
+}
+
+impl fmt::Display for Baseline {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(f, "# Median timings in nanoseconds, written by `adventofcode2015 bench --save`.")?;
+    for (&(day, stage), median) in &self.medians {
+      writeln!(f, "{} {} {}", day, stage, median.as_nanos())?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use registry;
+
+  fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+  }
+
+  #[test]
+  fn stats_from_samples_works() {
+    let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+    assert_eq!(Stats { runs: 3, min: ms(1), median: ms(3), max: ms(5) }, stats);
+
+    let stats = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+    assert_eq!(ms(3), stats.median);
+  }
+
+  #[test]
+  fn measure_counts_runs() {
+    let mut calls = 0;
+    assert_eq!(5, measure(5, || calls += 1).runs);
+    assert_eq!(5, calls);
+  }
+
+  #[test]
+  fn bench_day_reports_failing_parts() {
+    let entry = registry::get(1).unwrap();
+    assert_eq!(2, bench_day(&entry, "(()", &[1], 1).unwrap().len());
+    match bench_day(&entry, "(()", &[1, 2], 1) {
+      Err(Error::NoAnswer { day: 1, part: 2, .. }) => {},
+      other => panic!("Expected no answer, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn format_duration_works() {
+    assert_eq!("999 ns", format_duration(Duration::from_nanos(999)));
+    assert_eq!("1.50 µs", format_duration(Duration::from_nanos(1500)));
+    assert_eq!("12.00 ms", format_duration(ms(12)));
+    assert_eq!("2.50 s", format_duration(ms(2500)));
+  }
+
+  #[test]
+  fn baseline_round_trips() {
+    let mut baseline = Baseline::new();
+    baseline.record(1, Stage::Parse, &Stats::from_samples(vec![ms(1)]));
+    baseline.record(1, Stage::Part(2), &Stats::from_samples(vec![ms(7)]));
+
+    assert_eq!(baseline, Baseline::parse(&baseline.to_string()).unwrap());
+    assert!(Baseline::parse("1 parse").is_err());
+    assert!(Baseline::parse("1 part3x 10").is_err());
+  }
+
+  #[test]
+  fn compare_flags_regressions() {
+    let mut baseline = Baseline::new();
+    baseline.record(6, Stage::Part(1), &Stats::from_samples(vec![ms(100)]));
+
+    let compare = |median| baseline.compare(6, Stage::Part(1), &Stats::from_samples(vec![ms(median)]), 0.1);
+    assert_eq!(Some(Change::Regressed(0.5)), compare(150));
+    assert_eq!(Some(Change::Improved(-0.5)), compare(50));
+    assert_eq!(Some(Change::Unchanged(0.05)), compare(105));
+    assert_eq!(None, baseline.compare(6, Stage::Part(2), &Stats::from_samples(vec![ms(1)]), 0.1));
+  }
+}
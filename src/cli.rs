@@ -0,0 +1,869 @@
+use std::path::PathBuf;
+use day_three::{Compass, Direction, Edge, Hex, Step, Symbols, Town};
+use day_three::couriers::Dispatch;
+use day_two::cost::{self, PaperRoll, RibbonRoll};
+use day_two::units::{Measurer, Rounding, Unit};
+use export::Export;
+use image::ImageFormat;
+use input::Source;
+
+pub const USAGE: &str = "Usage:
+  adventofcode2015 run --day <N> [--part <1|2>] [--input <PATH|->] [--format <text|json>]
+  adventofcode2015 run --all [--part <1|2>] [--format <text|json>] [--jobs <N>]
+  adventofcode2015 verify [--day <N>] [--part <1|2>] [--input <PATH|->]
+                          [--answers <PATH>] [--record]
+  adventofcode2015 bench [--day <N>] [--part <1|2>] [--input <PATH|->]
+                         [--runs <N>] [--baseline <PATH>] [--save]
+                         [--threshold <PERCENT>] [--format <text|json>]
+  adventofcode2015 floor --target <F> [--all-steps] [--input <PATH|->]
+  adventofcode2015 trajectory [--input <PATH|->] [--export <csv|json>]
+  adventofcode2015 materials [--input <PATH|->] [--top <N>] [--export <csv|json>]
+  adventofcode2015 materials [--input <PATH|->] [--units <ft|in|cm>]
+                             [--output-units <ft|in|cm>]
+                             [--rounding <exact|up|down|nearest>]
+  adventofcode2015 cost --paper <WxL> --paper-price <PRICE> --ribbon <L>
+                        --ribbon-price <PRICE> [--input <PATH|->]
+  adventofcode2015 strategies [--input <PATH|->] [--slack-percent <N>]
+                              [--margin <FEET>] [--bag-ratio <N>]
+  adventofcode2015 pack --crate <LxWxH> [--exact] [--input <PATH|->]
+  adventofcode2015 couriers [DELIVERY]
+  adventofcode2015 visits [--at-least <K>] [DELIVERY]
+  adventofcode2015 heatmap --output <PATH.png|PATH.ppm> [--scale <N>] [--routes]
+                           [DELIVERY]
+
+where DELIVERY is any of
+  [--input <PATH|->] [--moves <4|8|hex>] [--symbols <CHARS>]
+  [--couriers <N> | --weights <W,W,...> | --streams]
+  [--town <WxH> [--edge <error|clamp|wrap>]]
+
+By default each day reads its input from data/dayN. Pass --input with a
+path to read a different file, or with - to read from stdin.
+
+run --all solves the days on --jobs threads (1 by default) and finishes
+with a summary of each day's time and any failures.
+
+With --format json, run and bench print one JSON object per line instead
+of text.
+
+verify checks every answer against answers.toml, keyed by day, part and
+input hash. With --record, answers that are not known yet are saved.
+
+bench times parsing and each part over --runs runs (10 by default) and
+reports the min, median and max. Medians are compared against the
+baseline (target/bench-baseline by default), flagging any that are more
+than --threshold percent (10 by default) slower. --save overwrites the
+baseline with this run.
+
+floor streams the day 1 input and prints the first step at which Santa
+stands on floor F, or with --all-steps every such step. Step 0 is the
+start on the ground floor.
+
+trajectory prints how many steps Santa spends on each floor and his
+longest runs above and below ground. With --export it instead writes
+every (step, floor) pair as CSV or JSON, ready for plotting.
+
+materials prints the paper and ribbon the day 2 presents need in total,
+along with the --top presents (5 by default) needing the most paper.
+With --export it instead writes a row per present and a total row.
+Given --units, --output-units or --rounding, the presents may have
+fractional dimensions in --units (feet by default) and only the totals
+are printed, in --output-units (the input units by default) with each
+present rounded by --rounding (exact by default).
+
+cost works out how many rolls of paper (W feet wide, L feet long) and
+ribbon (L feet long) to buy for the day 2 presents, cutting a separate
+piece for each present, and what they cost at the given prices.
+
+strategies compares the paper, ribbon and gift bags the day 2 presents
+need under different ways of wrapping them: the elves' smallest side
+slack, --slack-percent slack (10 by default), a --margin foot overlap
+(1 by default), and putting presents more than --bag-ratio times (4 by
+default) longer than they are thin into gift bags.
+
+pack packs the day 2 presents, turned whichever way suits, into crates of
+the given size and reports how many crates it took and how full they are.
+--exact searches for the fewest crates, but only takes small lists.
+
+couriers shares the day 3 directions out between couriers and prints how
+many houses each of them and all of them together visit. --couriers
+couriers (2 by default) take one direction each in turn, while with
+--weights each courier takes as many directions in a row as its weight.
+With --streams every line of the input is a separate courier's route.
+
+The day 3 commands read arrows moving the four ways by default. --moves 8
+adds the diagonals, read from the digits around 5 on a numeric keypad,
+and --moves hex moves on a grid of hexagons with the keypad's 8 9 3 2 1 7
+for north, north-east, south-east, south, south-west and north-west.
+--symbols replaces the model's symbols, listed clockwise from north.
+
+Couriers roam an endless town unless --town limits it to W by H houses
+around their starting point. Leaving it is an error unless --edge is
+clamp, which holds couriers at the edge, or wrap, which brings them back
+in on the far side.
+
+visits prints which house the couriers left the most presents at and
+how many houses got each number of presents. With --at-least it also
+counts the houses that got K or more.
+
+heatmap draws the houses the couriers visit as a PNG or PPM image, north
+up and --scale pixels (1 by default) to a house, colored from purple for
+a single present to yellow for the busiest house. --routes overlays each
+courier's route in a different color.";
+
+#[derive(Debug, PartialEq)]
+pub enum Days {
+  All,
+  One(usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Selection {
+  pub days: Days,
+  pub parts: Vec<usize>,
+  pub input: Source,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+  Text,
+  Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+  Run { selection: Selection, format: Format, jobs: usize },
+  Verify { selection: Selection, answers: PathBuf, record: bool },
+  Bench(Bench),
+  Floor { target: i64, all_steps: bool, input: Source },
+  Trajectory { input: Source, export: Option<Export> },
+  Materials { input: Source, top: usize, export: Option<Export>, measurer: Option<Measurer> },
+  Cost { input: Source, paper: PaperRoll, ribbon: RibbonRoll },
+  Strategies { input: Source, slack_percent: usize, margin: usize, bag_ratio: usize },
+  Pack { input: Source, crate_size: (usize, usize, usize), exact: bool },
+  Couriers(Delivery),
+  Visits { delivery: Delivery, at_least: Option<usize> },
+  Heatmap { delivery: Delivery, output: PathBuf, format: ImageFormat, scale: usize, routes: bool },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Bench {
+  pub selection: Selection,
+  pub runs: usize,
+  pub baseline: PathBuf,
+  pub save: bool,
+  pub threshold: f64,
+  pub format: Format,
+}
+
+fn parse_value(flag: &str, value: Option<String>) -> Result<String, String> {
+  value.ok_or_else(|| format!("Missing value for {}", flag))
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<usize, String> {
+  let value = parse_value(flag, value)?;
+  value.parse::<usize>()
+  .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+// Parses `n` numbers joined by `x`, such as `2x3x4`.
+fn parse_size(flag: &str, value: Option<String>, n: usize) -> Result<Vec<usize>, String> {
+  let value = parse_value(flag, value)?;
+  let size: Vec<usize> = value.split('x').filter_map(|side| side.parse().ok()).collect();
+  if size.len() != n || value.split('x').count() != n {
+    return Err(format!("Invalid value for {}: {}", flag, value));
+  }
+  Ok(size)
+}
+
+// Parses the flags every command shares, handing any other flag to
+// `other` along with the remaining arguments.
+fn parse_selection<I, F>(mut args: I, default_days: Option<Days>, mut other: F) -> Result<Selection, String>
+where I: Iterator<Item = String>, F: FnMut(&str, &mut I) -> Result<(), String> {
+  let mut days = None;
+  let mut parts = vec![1, 2];
+  let mut input = Source::Default;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--all" => days = Some(Days::All),
+      "--day" => days = Some(Days::One(parse_number("--day", args.next())?)),
+      "--part" => {
+        let part = parse_number("--part", args.next())?;
+        if part != 1 && part != 2 {
+          return Err(format!("Unknown part: {}", part));
+        }
+        parts = vec![part];
+      },
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      _ => other(&arg, &mut args)?,
+    }
+  }
+
+  let days = days.or(default_days)
+  .ok_or_else(|| "Either --day or --all is required".to_string())?;
+  if days == Days::All && input != Source::Default {
+    return Err("--input can only be used with a single --day".to_string());
+  }
+  Ok(Selection { days, parts, input })
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+  match parse_value("--format", value)?.as_str() {
+    "text" => Ok(Format::Text),
+    "json" => Ok(Format::Json),
+    other => Err(format!("Unknown format: {}", other)),
+  }
+}
+
+fn parse_export(value: Option<String>) -> Result<Export, String> {
+  match parse_value("--export", value)?.as_str() {
+    "csv" => Ok(Export::Csv),
+    "json" => Ok(Export::Json),
+    other => Err(format!("Unknown export format: {}", other)),
+  }
+}
+
+fn unexpected<I>(arg: &str, _: &mut I) -> Result<(), String> {
+  Err(format!("Unexpected argument: {}", arg))
+}
+
+fn parse_run<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+  let mut format = Format::Text;
+  let mut jobs = 1;
+
+  let selection = parse_selection(args, None, |arg, args| {
+    match arg {
+      "--format" => format = parse_format(args.next())?,
+      "--jobs" => jobs = parse_number("--jobs", args.next())?,
+      _ => return unexpected(arg, args),
+    }
+    Ok(())
+  })?;
+
+  if jobs == 0 {
+    return Err("--jobs must be at least 1".to_string());
+  }
+  Ok(Command::Run { selection, format, jobs })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+  let mut answers = PathBuf::from("answers.toml");
+  let mut record = false;
+
+  let selection = parse_selection(args, Some(Days::All), |arg, args| {
+    match arg {
+      "--answers" => answers = PathBuf::from(parse_value("--answers", args.next())?),
+      "--record" => record = true,
+      _ => return unexpected(arg, args),
+    }
+    Ok(())
+  })?;
+
+  Ok(Command::Verify { selection, answers, record })
+}
+
+fn parse_bench<I: Iterator<Item = String>>(args: I) -> Result<Command, String> {
+  let mut runs = 10;
+  let mut baseline = PathBuf::from("target/bench-baseline");
+  let mut save = false;
+  let mut threshold = 10.0;
+  let mut format = Format::Text;
+
+  let selection = parse_selection(args, Some(Days::All), |arg, args| {
+    match arg {
+      "--runs" => runs = parse_number("--runs", args.next())?,
+      "--baseline" => baseline = PathBuf::from(parse_value("--baseline", args.next())?),
+      "--save" => save = true,
+      "--threshold" => {
+        let value = parse_value("--threshold", args.next())?;
+        threshold = value.parse::<f64>()
+        .map_err(|_| format!("Invalid value for --threshold: {}", value))?;
+      },
+      "--format" => format = parse_format(args.next())?,
+      _ => return unexpected(arg, args),
+    }
+    Ok(())
+  })?;
+
+  if runs == 0 {
+    return Err("--runs must be at least 1".to_string());
+  }
+  Ok(Command::Bench(Bench { selection, runs, baseline, save, threshold: threshold / 100.0, format }))
+}
+
+fn parse_floor<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut target = None;
+  let mut all_steps = false;
+  let mut input = Source::Default;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--target" => {
+        let value = parse_value("--target", args.next())?;
+        target = Some(value.parse::<i64>()
+        .map_err(|_| format!("Invalid value for --target: {}", value))?);
+      },
+      "--all-steps" => all_steps = true,
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  let target = target.ok_or_else(|| "--target is required".to_string())?;
+  Ok(Command::Floor { target, all_steps, input })
+}
+
+fn parse_trajectory<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut input = Source::Default;
+  let mut export = None;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      "--export" => export = Some(parse_export(args.next())?),
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  Ok(Command::Trajectory { input, export })
+}
+
+fn parse_unit(flag: &str, value: Option<String>) -> Result<Unit, String> {
+  let value = parse_value(flag, value)?;
+  Unit::from_name(&value).ok_or_else(|| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_materials<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut input = Source::Default;
+  let mut top = None;
+  let mut export = None;
+  let (mut units, mut output_units, mut rounding) = (None, None, None);
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      "--top" => top = Some(parse_number("--top", args.next())?),
+      "--export" => export = Some(parse_export(args.next())?),
+      "--units" => units = Some(parse_unit("--units", args.next())?),
+      "--output-units" => output_units = Some(parse_unit("--output-units", args.next())?),
+      "--rounding" => {
+        let value = parse_value("--rounding", args.next())?;
+        rounding = Some(Rounding::from_name(&value).ok_or_else(|| format!("Invalid value for --rounding: {}", value))?);
+      },
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  let measurer = if units.is_some() || output_units.is_some() || rounding.is_some() {
+    if top.is_some() || export.is_some() {
+      return Err("--top and --export only work without --units, --output-units or --rounding".to_string());
+    }
+    let units = units.unwrap_or(Unit::Feet);
+    Some(Measurer::new(units, output_units.unwrap_or(units), rounding.unwrap_or(Rounding::Exact)))
+  } else {
+    None
+  };
+
+  Ok(Command::Materials { input, top: top.unwrap_or(5), export, measurer })
+}
+
+fn parse_price(flag: &str, value: Option<String>) -> Result<u64, String> {
+  let value = parse_value(flag, value)?;
+  cost::parse_price(&value)
+  .ok_or_else(|| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_cost<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut input = Source::Default;
+  let mut paper_size = None;
+  let mut paper_price = None;
+  let mut ribbon_length = None;
+  let mut ribbon_price = None;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      "--paper" => {
+        let size = parse_size("--paper", args.next(), 2)?;
+        paper_size = Some((size[0], size[1]));
+      },
+      "--paper-price" => paper_price = Some(parse_price("--paper-price", args.next())?),
+      "--ribbon" => ribbon_length = Some(parse_number("--ribbon", args.next())?),
+      "--ribbon-price" => ribbon_price = Some(parse_price("--ribbon-price", args.next())?),
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  let required = |flag: &str| format!("{} is required", flag);
+  let (width, length) = paper_size.ok_or_else(|| required("--paper"))?;
+  let paper = PaperRoll { width, length, price: paper_price.ok_or_else(|| required("--paper-price"))? };
+  let ribbon = RibbonRoll {
+    length: ribbon_length.ok_or_else(|| required("--ribbon"))?,
+    price: ribbon_price.ok_or_else(|| required("--ribbon-price"))?,
+  };
+  Ok(Command::Cost { input, paper, ribbon })
+}
+
+fn parse_strategies<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut input = Source::Default;
+  let mut slack_percent = 10;
+  let mut margin = 1;
+  let mut bag_ratio = 4;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      "--slack-percent" => slack_percent = parse_number("--slack-percent", args.next())?,
+      "--margin" => margin = parse_number("--margin", args.next())?,
+      "--bag-ratio" => bag_ratio = parse_number("--bag-ratio", args.next())?,
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  Ok(Command::Strategies { input, slack_percent, margin, bag_ratio })
+}
+
+fn parse_pack<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut input = Source::Default;
+  let mut crate_size = None;
+  let mut exact = false;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--input" => input = Source::from_arg(&parse_value("--input", args.next())?),
+      "--crate" => {
+        let size = parse_size("--crate", args.next(), 3)?;
+        crate_size = Some((size[0], size[1], size[2]));
+      },
+      "--exact" => exact = true,
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  let crate_size = crate_size.ok_or_else(|| "--crate is required".to_string())?;
+  Ok(Command::Pack { input, crate_size, exact })
+}
+
+/// How the day 3 directions are read.
+#[derive(Debug, PartialEq)]
+pub enum Moves {
+  Four(Symbols<Direction>),
+  Eight(Symbols<Compass>),
+  Hex(Symbols<Hex>),
+}
+
+/// Where the day 3 directions come from and how couriers follow them.
+#[derive(Debug, PartialEq)]
+pub struct Delivery {
+  pub input: Source,
+  pub dispatch: Dispatch,
+  pub moves: Moves,
+  pub town: Town,
+}
+
+// The flags every day 3 delivery command shares.
+struct DeliveryFlags {
+  input: Source,
+  dispatch: Option<Dispatch>,
+  moves: Option<String>,
+  symbols: Option<String>,
+  size: Option<(usize, usize)>,
+  edge: Option<Edge>,
+}
+
+impl DeliveryFlags {
+  fn new() -> Self {
+    DeliveryFlags { input: Source::Default, dispatch: None, moves: None, symbols: None, size: None, edge: None }
+  }
+
+  // Takes `arg` if it is one of the shared flags, returning whether it was.
+  fn parse<I: Iterator<Item = String>>(&mut self, arg: &str, args: &mut I) -> Result<bool, String> {
+    let dispatch = match arg {
+      "--input" => {
+        self.input = Source::from_arg(&parse_value("--input", args.next())?);
+        return Ok(true);
+      },
+      "--moves" => {
+        self.moves = Some(parse_value("--moves", args.next())?);
+        return Ok(true);
+      },
+      "--symbols" => {
+        self.symbols = Some(parse_value("--symbols", args.next())?);
+        return Ok(true);
+      },
+      "--town" => {
+        let size = parse_size("--town", args.next(), 2)?;
+        self.size = Some((size[0], size[1]));
+        return Ok(true);
+      },
+      "--edge" => {
+        let value = parse_value("--edge", args.next())?;
+        self.edge = Some(Edge::from_name(&value).ok_or_else(|| format!("Invalid value for --edge: {}", value))?);
+        return Ok(true);
+      },
+      "--couriers" => Dispatch::RoundRobin(parse_number("--couriers", args.next())?),
+      "--weights" => {
+        let value = parse_value("--weights", args.next())?;
+        let weights = value.split(',').map(|weight| weight.parse::<usize>()).collect::<Result<Vec<usize>, _>>()
+        .map_err(|_| format!("Invalid value for --weights: {}", value))?;
+        Dispatch::Weighted(weights)
+      },
+      "--streams" => Dispatch::Streams,
+      _ => return Ok(false),
+    };
+    if self.dispatch.is_some() {
+      return Err("Only one of --couriers, --weights and --streams may be given".to_string());
+    }
+    self.dispatch = Some(dispatch);
+    Ok(true)
+  }
+
+  fn finish(self) -> Result<Delivery, String> {
+    fn symbols<D: Step>(custom: Option<String>, default: Symbols<D>) -> Result<Symbols<D>, String> {
+      match custom {
+        Some(chars) => Symbols::from_chars(&chars).map_err(|err| format!("Invalid value for --symbols: {}", err)),
+        None => Ok(default),
+      }
+    }
+
+    let moves = match self.moves.as_deref().unwrap_or("4") {
+      "4" => Moves::Four(symbols(self.symbols, Symbols::four_way())?),
+      "8" => Moves::Eight(symbols(self.symbols, Symbols::eight_way())?),
+      "hex" => Moves::Hex(symbols(self.symbols, Symbols::hex())?),
+      other => return Err(format!("Invalid value for --moves: {}", other)),
+    };
+
+    let town = match (self.size, self.edge) {
+      (Some((width, height)), edge) => Town::bounded(width, height, edge.unwrap_or(Edge::Error))
+        .map_err(|err| format!("Invalid value for --town: {}", err))?,
+      (None, Some(_)) => return Err("--edge needs a --town".to_string()),
+      (None, None) => Town::unbounded(),
+    };
+
+    Ok(Delivery { input: self.input, dispatch: self.dispatch.unwrap_or(Dispatch::RoundRobin(2)), moves, town })
+  }
+}
+
+fn parse_couriers<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut delivery = DeliveryFlags::new();
+
+  while let Some(arg) = args.next() {
+    if !delivery.parse(&arg, &mut args)? {
+      return Err(format!("Unexpected argument: {}", arg));
+    }
+  }
+
+  Ok(Command::Couriers(delivery.finish()?))
+}
+
+fn parse_visits<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut delivery = DeliveryFlags::new();
+  let mut at_least = None;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--at-least" => at_least = Some(parse_number("--at-least", args.next())?),
+      _ if delivery.parse(&arg, &mut args)? => (),
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  Ok(Command::Visits { delivery: delivery.finish()?, at_least })
+}
+
+fn parse_heatmap<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  let mut delivery = DeliveryFlags::new();
+  let mut output = None;
+  let mut scale = 1;
+  let mut routes = false;
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--output" => output = Some(parse_value("--output", args.next())?),
+      "--scale" => scale = parse_number("--scale", args.next())?,
+      "--routes" => routes = true,
+      _ if delivery.parse(&arg, &mut args)? => (),
+      _ => return Err(format!("Unexpected argument: {}", arg)),
+    }
+  }
+
+  let output = output.ok_or_else(|| "--output is required".to_string())?;
+  let format = ImageFormat::from_file_name(&output)
+  .ok_or_else(|| format!("--output must end in .png or .ppm: {}", output))?;
+  if scale == 0 {
+    return Err("--scale must be at least 1".to_string());
+  }
+
+  Ok(Command::Heatmap {
+    delivery: delivery.finish()?,
+    output: PathBuf::from(output),
+    format,
+    scale,
+    routes,
+  })
+}
+
+pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+  match args.next() {
+    Some(ref cmd) if cmd == "run" => parse_run(args),
+    Some(ref cmd) if cmd == "verify" => parse_verify(args),
+    Some(ref cmd) if cmd == "bench" => parse_bench(args),
+    Some(ref cmd) if cmd == "floor" => parse_floor(args),
+    Some(ref cmd) if cmd == "trajectory" => parse_trajectory(args),
+    Some(ref cmd) if cmd == "materials" => parse_materials(args),
+    Some(ref cmd) if cmd == "cost" => parse_cost(args),
+    Some(ref cmd) if cmd == "strategies" => parse_strategies(args),
+    Some(ref cmd) if cmd == "pack" => parse_pack(args),
+    Some(ref cmd) if cmd == "couriers" => parse_couriers(args),
+    Some(ref cmd) if cmd == "visits" => parse_visits(args),
+    Some(ref cmd) if cmd == "heatmap" => parse_heatmap(args),
+    Some(cmd) => Err(format!("Unknown command: {}", cmd)),
+    None => Err("No command given".to_string()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+  }
+
+  #[test]
+  fn parse_run_day_and_part_works() {
+    let cmd = parse(args("run --day 3 --part 2").into_iter());
+    let selection = Selection { days: Days::One(3), parts: vec![2], input: Source::Default };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Text, jobs: 1 }), cmd);
+  }
+
+  #[test]
+  fn parse_run_all_works() {
+    let cmd = parse(args("run --all").into_iter());
+    let selection = Selection { days: Days::All, parts: vec![1, 2], input: Source::Default };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Text, jobs: 1 }), cmd);
+  }
+
+  #[test]
+  fn parse_run_input_works() {
+    let cmd = parse(args("run --day 1 --input -").into_iter());
+    let selection = Selection { days: Days::One(1), parts: vec![1, 2], input: Source::Stdin };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Text, jobs: 1 }), cmd);
+
+    let cmd = parse(args("run --day 1 --input my/day1").into_iter());
+    let input = Source::Path("my/day1".into());
+    let selection = Selection { days: Days::One(1), parts: vec![1, 2], input };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Text, jobs: 1 }), cmd);
+  }
+
+  #[test]
+  fn parse_format_works() {
+    let cmd = parse(args("run --all --format json").into_iter());
+    let selection = Selection { days: Days::All, parts: vec![1, 2], input: Source::Default };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Json, jobs: 1 }), cmd);
+  }
+
+  #[test]
+  fn parse_jobs_works() {
+    let cmd = parse(args("run --all --jobs 4").into_iter());
+    let selection = Selection { days: Days::All, parts: vec![1, 2], input: Source::Default };
+    assert_eq!(Ok(Command::Run { selection, format: Format::Text, jobs: 4 }), cmd);
+  }
+
+  #[test]
+  fn parse_verify_works() {
+    let cmd = parse(args("verify").into_iter());
+    let selection = Selection { days: Days::All, parts: vec![1, 2], input: Source::Default };
+    let answers = PathBuf::from("answers.toml");
+    assert_eq!(Ok(Command::Verify { selection, answers, record: false }), cmd);
+
+    let cmd = parse(args("verify --day 2 --answers mine.toml --record").into_iter());
+    let selection = Selection { days: Days::One(2), parts: vec![1, 2], input: Source::Default };
+    let answers = PathBuf::from("mine.toml");
+    assert_eq!(Ok(Command::Verify { selection, answers, record: true }), cmd);
+  }
+
+  #[test]
+  fn parse_bench_works() {
+    let cmd = parse(args("bench --day 6 --part 1 --runs 3 --save --threshold 25").into_iter());
+    let selection = Selection { days: Days::One(6), parts: vec![1], input: Source::Default };
+    let bench = Bench {
+      selection,
+      runs: 3,
+      baseline: PathBuf::from("target/bench-baseline"),
+      save: true,
+      threshold: 0.25,
+      format: Format::Text,
+    };
+    assert_eq!(Ok(Command::Bench(bench)), cmd);
+  }
+
+  #[test]
+  fn parse_floor_works() {
+    let cmd = parse(args("floor --target -3").into_iter());
+    assert_eq!(Ok(Command::Floor { target: -3, all_steps: false, input: Source::Default }), cmd);
+
+    let cmd = parse(args("floor --all-steps --input - --target 2").into_iter());
+    assert_eq!(Ok(Command::Floor { target: 2, all_steps: true, input: Source::Stdin }), cmd);
+  }
+
+  #[test]
+  fn parse_trajectory_works() {
+    let cmd = parse(args("trajectory").into_iter());
+    assert_eq!(Ok(Command::Trajectory { input: Source::Default, export: None }), cmd);
+
+    let cmd = parse(args("trajectory --export csv --input -").into_iter());
+    assert_eq!(Ok(Command::Trajectory { input: Source::Stdin, export: Some(Export::Csv) }), cmd);
+  }
+
+  #[test]
+  fn parse_materials_works() {
+    let cmd = parse(args("materials").into_iter());
+    assert_eq!(Ok(Command::Materials { input: Source::Default, top: 5, export: None, measurer: None }), cmd);
+
+    let cmd = parse(args("materials --top 3 --export json").into_iter());
+    let expected = Command::Materials { input: Source::Default, top: 3, export: Some(Export::Json), measurer: None };
+    assert_eq!(Ok(expected), cmd);
+
+    let cmd = parse(args("materials --units cm --rounding up").into_iter());
+    let measurer = Measurer::new(Unit::Centimetres, Unit::Centimetres, Rounding::Up);
+    assert_eq!(Ok(Command::Materials { input: Source::Default, top: 5, export: None, measurer: Some(measurer) }), cmd);
+
+    let cmd = parse(args("materials --output-units in").into_iter());
+    let measurer = Measurer::new(Unit::Feet, Unit::Inches, Rounding::Exact);
+    assert_eq!(Ok(Command::Materials { input: Source::Default, top: 5, export: None, measurer: Some(measurer) }), cmd);
+  }
+
+  #[test]
+  fn parse_cost_works() {
+    let cmd = parse(args("cost --paper 3x100 --paper-price 12.99 --ribbon 50 --ribbon-price 4.5").into_iter());
+    let paper = PaperRoll { width: 3, length: 100, price: 1299 };
+    let ribbon = RibbonRoll { length: 50, price: 450 };
+    assert_eq!(Ok(Command::Cost { input: Source::Default, paper, ribbon }), cmd);
+  }
+
+  #[test]
+  fn parse_strategies_works() {
+    let cmd = parse(args("strategies --margin 2").into_iter());
+    assert_eq!(Ok(Command::Strategies { input: Source::Default, slack_percent: 10, margin: 2, bag_ratio: 4 }), cmd);
+  }
+
+  #[test]
+  fn parse_pack_works() {
+    let cmd = parse(args("pack --crate 30x40x50 --exact").into_iter());
+    assert_eq!(Ok(Command::Pack { input: Source::Default, crate_size: (30, 40, 50), exact: true }), cmd);
+  }
+
+  fn delivery(input: Source, dispatch: Dispatch) -> Delivery {
+    Delivery { input, dispatch, moves: Moves::Four(Symbols::four_way()), town: Town::unbounded() }
+  }
+
+  #[test]
+  fn parse_couriers_works() {
+    assert_eq!(Ok(Command::Couriers(delivery(Source::Default, Dispatch::RoundRobin(2)))), parse(args("couriers").into_iter()));
+    assert_eq!(Ok(Command::Couriers(delivery(Source::Default, Dispatch::Weighted(vec![2, 1])))),
+      parse(args("couriers --weights 2,1").into_iter()));
+    assert_eq!(Ok(Command::Couriers(delivery(Source::Stdin, Dispatch::Streams))),
+      parse(args("couriers --streams --input -").into_iter()));
+  }
+
+  #[test]
+  fn parse_moves_works() {
+    match parse(args("couriers --moves 8").into_iter()) {
+      Ok(Command::Couriers(delivery)) => assert_eq!(Moves::Eight(Symbols::eight_way()), delivery.moves),
+      other => panic!("Expected a couriers command, got {:?}", other),
+    }
+    match parse(args("couriers --symbols wdsa").into_iter()) {
+      Ok(Command::Couriers(delivery)) => assert_eq!(Moves::Four(Symbols::from_chars("wdsa").unwrap()), delivery.moves),
+      other => panic!("Expected a couriers command, got {:?}", other),
+    }
+    match parse(args("visits --moves hex --symbols abcdef").into_iter()) {
+      Ok(Command::Visits { delivery, .. }) => assert_eq!(Moves::Hex(Symbols::from_chars("abcdef").unwrap()), delivery.moves),
+      other => panic!("Expected a visits command, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn parse_town_works() {
+    match parse(args("couriers --town 40x30 --edge wrap").into_iter()) {
+      Ok(Command::Couriers(delivery)) => assert_eq!(Town::bounded(40, 30, Edge::Wrap).unwrap(), delivery.town),
+      other => panic!("Expected a couriers command, got {:?}", other),
+    }
+    match parse(args("visits --town 5x5").into_iter()) {
+      Ok(Command::Visits { delivery, .. }) => assert_eq!(Town::bounded(5, 5, Edge::Error).unwrap(), delivery.town),
+      other => panic!("Expected a visits command, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn parse_visits_works() {
+    assert_eq!(Ok(Command::Visits { delivery: delivery(Source::Default, Dispatch::RoundRobin(2)), at_least: None }),
+      parse(args("visits").into_iter()));
+    assert_eq!(Ok(Command::Visits { delivery: delivery(Source::Default, Dispatch::RoundRobin(1)), at_least: Some(3) }),
+      parse(args("visits --couriers 1 --at-least 3").into_iter()));
+  }
+
+  #[test]
+  fn parse_heatmap_works() {
+    let cmd = parse(args("heatmap --output map.png --routes --couriers 3").into_iter());
+    assert_eq!(Ok(Command::Heatmap {
+      delivery: delivery(Source::Default, Dispatch::RoundRobin(3)),
+      output: PathBuf::from("map.png"),
+      format: ImageFormat::Png,
+      scale: 1,
+      routes: true,
+    }), cmd);
+
+    match parse(args("heatmap --scale 4 --output map.ppm").into_iter()) {
+      Ok(Command::Heatmap { format, scale, .. }) => assert_eq!((ImageFormat::Ppm, 4), (format, scale)),
+      other => panic!("Expected a heatmap command, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn parse_rejects_bad_input() {
+    assert!(parse(args("").into_iter()).is_err());
+    assert!(parse(args("walk --all").into_iter()).is_err());
+    assert!(parse(args("run").into_iter()).is_err());
+    assert!(parse(args("run --day").into_iter()).is_err());
+    assert!(parse(args("run --day three").into_iter()).is_err());
+    assert!(parse(args("run --all --part 3").into_iter()).is_err());
+    assert!(parse(args("run --all --input -").into_iter()).is_err());
+    assert!(parse(args("run --day 1 --input").into_iter()).is_err());
+    assert!(parse(args("run --day 1 --record").into_iter()).is_err());
+    assert!(parse(args("verify --answers").into_iter()).is_err());
+    assert!(parse(args("bench --threshold fast").into_iter()).is_err());
+    assert!(parse(args("bench --runs 0").into_iter()).is_err());
+    assert!(parse(args("run --all --format yaml").into_iter()).is_err());
+    assert!(parse(args("verify --format json").into_iter()).is_err());
+    assert!(parse(args("run --all --jobs 0").into_iter()).is_err());
+    assert!(parse(args("floor").into_iter()).is_err());
+    assert!(parse(args("floor --target up").into_iter()).is_err());
+    assert!(parse(args("floor --target 1 --day 1").into_iter()).is_err());
+    assert!(parse(args("trajectory --export xml").into_iter()).is_err());
+    assert!(parse(args("materials --top").into_iter()).is_err());
+    assert!(parse(args("materials --units yards").into_iter()).is_err());
+    assert!(parse(args("materials --rounding sideways").into_iter()).is_err());
+    assert!(parse(args("materials --units in --export csv").into_iter()).is_err());
+    assert!(parse(args("cost --paper 3x100 --paper-price 1 --ribbon 50").into_iter()).is_err());
+    assert!(parse(args("cost --paper 3 --paper-price 1 --ribbon 50 --ribbon-price 1").into_iter()).is_err());
+    assert!(parse(args("cost --paper 3x1x1 --paper-price 1 --ribbon 50 --ribbon-price 1").into_iter()).is_err());
+    assert!(parse(args("cost --paper 3x100 --paper-price $1 --ribbon 50 --ribbon-price 1").into_iter()).is_err());
+    assert!(parse(args("strategies --bag-ratio wide").into_iter()).is_err());
+    assert!(parse(args("pack").into_iter()).is_err());
+    assert!(parse(args("pack --crate 30x40").into_iter()).is_err());
+    assert!(parse(args("couriers --weights 2,,1").into_iter()).is_err());
+    assert!(parse(args("couriers --couriers 3 --streams").into_iter()).is_err());
+    assert!(parse(args("visits --at-least").into_iter()).is_err());
+    assert!(parse(args("couriers --moves 6").into_iter()).is_err());
+    assert!(parse(args("couriers --moves 8 --symbols ^>v<").into_iter()).is_err());
+    assert!(parse(args("couriers --symbols aaaa").into_iter()).is_err());
+    assert!(parse(args("couriers --town 40").into_iter()).is_err());
+    assert!(parse(args("couriers --town 0x30").into_iter()).is_err());
+    assert!(parse(args("couriers --town 40x30 --edge bounce").into_iter()).is_err());
+    assert!(parse(args("couriers --edge wrap").into_iter()).is_err());
+    assert!(parse(args("heatmap").into_iter()).is_err());
+    assert!(parse(args("heatmap --output map.gif").into_iter()).is_err());
+    assert!(parse(args("heatmap --output map.png --scale 0").into_iter()).is_err());
+  }
+}
@@ -1,6 +1,9 @@
 use crypto::digest::Digest;
 use crypto::md5::Md5;
+use error::{ParseError, Result};
+use solution::{Answer, Solution};
 
+#[allow(clippy::needless_return)]
 fn candidate_has_zeroes(candidate: String, zeroes: usize) -> bool {
   let slice = &candidate[0..zeroes];
   for ch in slice.chars() {
@@ -18,7 +21,9 @@ fn hash_candidate(prefix: &str, suffix: usize) -> String {
   hasher.result_str()
 }
 
-fn find_suffix_with_zeroes(prefix: &str, n_zeroes: usize) -> usize {
+/// Finds the lowest number which, appended to `prefix`, produces an MD5
+/// hash starting with `n_zeroes` zeroes.
+pub fn find_suffix_with_zeroes(prefix: &str, n_zeroes: usize) -> usize {
   let mut suffix = 0;
   let mut candidate = hash_candidate(prefix, suffix);
   while !candidate_has_zeroes(candidate, n_zeroes) {
@@ -28,20 +33,28 @@ fn find_suffix_with_zeroes(prefix: &str, n_zeroes: usize) -> usize {
   suffix
 }
 
-fn part_1(prefix: &str) {
-  let suffix = find_suffix_with_zeroes(prefix, 5);
-  println!("First suffix with 5 zeros = {:?}", suffix);
-}
+pub struct DayFour;
 
-fn part_2(prefix: &str) {
-  let suffix = find_suffix_with_zeroes(prefix, 6);
-  println!("First suffix with 6 zeros = {:?}", suffix);
-}
+impl Solution for DayFour {
+  type Input = String;
+
+  fn parse(&self, raw: &str) -> Result<String> {
+    let prefix = raw.trim();
+    if prefix.is_empty() {
+      return Err(ParseError::new(4, 1, 1, "", "expected a secret key").into());
+    }
+    Ok(prefix.to_string())
+  }
+
+  fn part_one(&self, prefix: &String) -> Result<Answer> {
+    let suffix = find_suffix_with_zeroes(prefix, 5);
+    Ok(Answer::new("First suffix with 5 zeros", suffix as i64))
+  }
 
-pub fn main() {
-  let prefix = "ckczppom";
-  part_1(&prefix);
-  part_2(&prefix);
+  fn part_two(&self, prefix: &String) -> Result<Answer> {
+    let suffix = find_suffix_with_zeroes(prefix, 6);
+    Ok(Answer::new("First suffix with 6 zeros", suffix as i64))
+  }
 }
 
 #[cfg(test)]
@@ -1,38 +1,587 @@
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind, Read, Write};
+use error::{Error, ParseError, Result};
+use export::Export;
+use json::Object;
+use solution::{Answer, Solution};
 
-fn part_1() {
-  let end_floor: i64 = include_str!("../data/day1").chars()
-  .map(|ch| match ch {
-    '(' => 1,
-    ')' => -1,
-    _ => panic!("Invalid input character: {}", ch),
-  })
-  .fold(0, |sum, n| sum + n);
+/// Follows Santa up and down the building one instruction at a time,
+/// keeping only a handful of counters so input of any size can be streamed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloorTracker {
+  floor: i64,
+  steps: usize,
+  basement: Option<usize>,
+  min: i64,
+  max: i64,
+  line: usize,
+  column: usize,
+}
+
+impl Default for FloorTracker {
+  fn default() -> Self {
+    FloorTracker::new()
+  }
+}
+
+impl FloorTracker {
+  pub fn new() -> Self {
+    FloorTracker {
+      floor: 0,
+      steps: 0,
+      basement: None,
+      min: 0,
+      max: 0,
+      line: 1,
+      column: 0,
+    }
+  }
+
+  /// Applies a single byte of input. Line breaks are skipped, anything other
+  /// than `(` or `)` is an error.
+  pub fn step(&mut self, byte: u8) -> Result<()> {
+    self.column += 1;
+
+    let delta = match byte {
+      b'(' => 1,
+      b')' => -1,
+      b'\r' => return Ok(()),
+      b'\n' => {
+        self.line += 1;
+        self.column = 0;
+        return Ok(());
+      },
+      _ => {
+        let token = if byte.is_ascii() {
+          (byte as char).to_string()
+        } else {
+          format!("\\x{:02x}", byte)
+        };
+        return Err(ParseError::new(1, self.line, self.column, &token, "expected `(` or `)`").into());
+      }
+    };
+
+    self.floor += delta;
+    self.steps += 1;
+    if self.floor < self.min {
+      self.min = self.floor;
+    }
+    if self.floor > self.max {
+      self.max = self.floor;
+    }
+    if self.floor == -1 && self.basement.is_none() {
+      self.basement = Some(self.steps);
+    }
+    Ok(())
+  }
+
+  pub fn feed(&mut self, bytes: &[u8]) -> Result<()> {
+    for &byte in bytes {
+      self.step(byte)?;
+    }
+    Ok(())
+  }
+
+  /// Consumes `reader` to the end in fixed-size chunks.
+  pub fn track<R: Read>(reader: R) -> Result<Self> {
+    FloorTracker::track_while(reader, |_| true)
+  }
+
+  /// Like `track`, but calls `visit` after every move and stops reading as
+  /// soon as it returns false.
+  pub fn track_while<R, F>(mut reader: R, mut visit: F) -> Result<Self>
+  where R: Read, F: FnMut(&Self) -> bool {
+    let mut tracker = FloorTracker::new();
+    let mut buffer = [0; 64 * 1024];
+
+    loop {
+      let read = match reader.read(&mut buffer) {
+        Ok(0) => return Ok(tracker),
+        Ok(read) => read,
+        Err(ref err) if err.kind() == ErrorKind::Interrupted => continue,
+        Err(err) => return Err(err.into()),
+      };
+
+      for &byte in &buffer[..read] {
+        let steps = tracker.steps;
+        tracker.step(byte)?;
+        if tracker.steps != steps && !visit(&tracker) {
+          return Ok(tracker);
+        }
+      }
+    }
+  }
+
+  pub fn floor(&self) -> i64 {
+    self.floor
+  }
+
+  pub fn steps(&self) -> usize {
+    self.steps
+  }
+
+  /// The 1-based step at which Santa first entered the basement.
+  pub fn first_basement(&self) -> Option<usize> {
+    self.basement
+  }
+
+  pub fn min_floor(&self) -> i64 {
+    self.min
+  }
+
+  pub fn max_floor(&self) -> i64 {
+    self.max
+  }
+}
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const LOW_BITS: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// How many of each interesting byte a slice holds.
+#[derive(Debug, Default, PartialEq)]
+struct Counts {
+  up: usize,
+  down: usize,
+  breaks: usize,
+}
+
+// Number of bytes in `word` equal to `byte`. The high bit of each byte of
+// `nonzero` is set exactly when that byte of `x` is not zero, without any
+// carries leaking between bytes.
+fn count_matches(word: u64, byte: u8) -> usize {
+  let x = word ^ (u64::from(byte) * ONES);
+  let nonzero = ((x & LOW_BITS) + LOW_BITS) | x;
+  (!nonzero & HIGH_BITS).count_ones() as usize
+}
+
+fn count_scalar(bytes: &[u8]) -> Counts {
+  let mut counts = Counts::default();
+  for &byte in bytes {
+    match byte {
+      b'(' => counts.up += 1,
+      b')' => counts.down += 1,
+      b'\n' | b'\r' => counts.breaks += 1,
+      _ => {}
+    }
+  }
+  counts
+}
+
+// Counts eight bytes at a time, leaving the tail to `count_scalar`.
+fn count_chunked(bytes: &[u8]) -> Counts {
+  let mut counts = Counts::default();
+  let mut chunks = bytes.chunks_exact(8);
+
+  for chunk in &mut chunks {
+    let mut word = [0; 8];
+    word.copy_from_slice(chunk);
+    let word = u64::from_ne_bytes(word);
+
+    counts.up += count_matches(word, b'(');
+    counts.down += count_matches(word, b')');
+    counts.breaks += count_matches(word, b'\n') + count_matches(word, b'\r');
+  }
+
+  let tail = count_scalar(chunks.remainder());
+  counts.up += tail.up;
+  counts.down += tail.down;
+  counts.breaks += tail.breaks;
+  counts
+}
+
+/// The floor Santa ends up on, counting brackets a word at a time instead of
+/// stepping through them. Falls back to `FloorTracker` to report where the
+/// input goes wrong when it holds anything other than brackets and line
+/// breaks.
+pub fn final_floor(bytes: &[u8]) -> Result<i64> {
+  let counts = count_chunked(bytes);
+  if counts.up + counts.down + counts.breaks != bytes.len() {
+    FloorTracker::new().feed(bytes)?;
+  }
+  Ok(counts.up as i64 - counts.down as i64)
+}
+
+/// The first step at which Santa stands on `floor`, where step 0 is the
+/// start on the ground floor. Stops reading as soon as the floor is reached.
+pub fn first_step_at<R: Read>(reader: R, floor: i64) -> Result<Option<usize>> {
+  if floor == 0 {
+    return Ok(Some(0));
+  }
 
-  println!("End floor: {}", end_floor);
+  let tracker = FloorTracker::track_while(reader, |tracker| tracker.floor() != floor)?;
+  if tracker.floor() == floor {
+    Ok(Some(tracker.steps()))
+  } else {
+    Ok(None)
+  }
 }
 
-fn part_2() {
-  let steps: Vec<i8> = include_str!("../data/day1").chars()
-  .map(|ch| match ch {
-    '(' => 1,
-    ')' => -1,
-    _ => panic!("Invalid input character: {}", ch),
-  }).collect();
+/// Every step at which Santa stands on `floor`, in order.
+pub fn steps_at<R: Read>(reader: R, floor: i64) -> Result<Vec<usize>> {
+  let mut steps = if floor == 0 { vec![0] } else { vec![] };
 
-  let mut floor: isize = 0;
-  let mut result = 0;
-  for (i, &step) in steps.iter().enumerate() {
-    floor += step as isize;
-    if floor == -1 {
-      result = i + 1;
-      break;
+  FloorTracker::track_while(reader, |tracker| {
+    if tracker.floor() == floor {
+      steps.push(tracker.steps());
     }
+    true
+  })?;
+  Ok(steps)
+}
+
+/// A stretch of consecutive steps spent on the same side of the ground floor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Run {
+  /// The first step of the stretch.
+  pub start: usize,
+  pub len: usize,
+}
+
+/// Statistics over Santa's whole trip through the building.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trajectory {
+  histogram: BTreeMap<i64, usize>,
+  above: Option<Run>,
+  below: Option<Run>,
+  current: Option<(i64, Run)>,
+}
+
+impl Trajectory {
+  /// Records Santa standing on `floor` at `step`. Steps must be recorded in
+  /// order, starting from 0.
+  pub fn record(&mut self, step: usize, floor: i64) {
+    *self.histogram.entry(floor).or_insert(0) += 1;
+
+    let side = floor.signum();
+    let run = match self.current {
+      Some((current, ref mut run)) if current == side => {
+        run.len += 1;
+        *run
+      },
+      _ => {
+        let run = Run { start: step, len: 1 };
+        self.current = Some((side, run));
+        run
+      }
+    };
+
+    let longest = match side {
+      1 => &mut self.above,
+      -1 => &mut self.below,
+      _ => return,
+    };
+    if longest.is_none_or(|longest| run.len > longest.len) {
+      *longest = Some(run);
+    }
+  }
+
+  /// How many steps Santa spent on each floor, counting the start on the
+  /// ground floor as step 0.
+  pub fn histogram(&self) -> &BTreeMap<i64, usize> {
+    &self.histogram
+  }
+
+  pub fn longest_above_ground(&self) -> Option<Run> {
+    self.above
   }
 
-  println!("Reached -1 at step: {:?}", result);
+  pub fn longest_below_ground(&self) -> Option<Run> {
+    self.below
+  }
 }
 
-pub fn main () {
-  part_1();
-  part_2();
+/// Follows the whole input, collecting a `Trajectory`.
+pub fn trajectory<R: Read>(reader: R) -> Result<Trajectory> {
+  let mut trajectory = Trajectory::default();
+  trajectory.record(0, 0);
+
+  FloorTracker::track_while(reader, |tracker| {
+    trajectory.record(tracker.steps(), tracker.floor());
+    true
+  })?;
+  Ok(trajectory)
+}
+
+/// Writes every `(step, floor)` pair of the trip to `out`, starting with
+/// step 0 on the ground floor.
+pub fn export_trajectory<R: Read, W: Write>(reader: R, mut out: W, format: Export) -> Result<()> {
+  fn write_point<W: Write>(out: &mut W, format: Export, step: usize, floor: i64) -> io::Result<()> {
+    match format {
+      Export::Csv => writeln!(out, "{},{}", step, floor),
+      Export::Json if step == 0 => write!(out, "[\n{}", Object::new().field("step", step).field("floor", floor)),
+      Export::Json => write!(out, ",\n{}", Object::new().field("step", step).field("floor", floor)),
+    }
+  }
+
+  if format == Export::Csv {
+    writeln!(out, "step,floor")?;
+  }
+  write_point(&mut out, format, 0, 0)?;
+
+  let mut failed = None;
+  FloorTracker::track_while(reader, |tracker| {
+    match write_point(&mut out, format, tracker.steps(), tracker.floor()) {
+      Ok(()) => true,
+      Err(err) => {
+        failed = Some(err);
+        false
+      }
+    }
+  })?;
+
+  if let Some(err) = failed {
+    return Err(err.into());
+  }
+  if format == Export::Json {
+    writeln!(out, "\n]")?;
+  }
+  out.flush()?;
+  Ok(())
+}
+
+/// What the puzzle asks about a list of instructions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Floors {
+  end: i64,
+  basement: Option<usize>,
+}
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+  type Input = Floors;
+
+  // The end floor is counted a word at a time over the whole input; the
+  // tracker only has to walk as far as the basement.
+  fn parse(&self, raw: &str) -> Result<Floors> {
+    let end = final_floor(raw.as_bytes())?;
+    let basement = first_step_at(raw.as_bytes(), -1)?;
+    Ok(Floors { end, basement })
+  }
+
+  fn part_one(&self, floors: &Floors) -> Result<Answer> {
+    Ok(Answer::new("End floor", floors.end))
+  }
+
+  fn part_two(&self, floors: &Floors) -> Result<Answer> {
+    let result = floors.basement.ok_or_else(|| Error::NoAnswer {
+      day: 1,
+      part: 2,
+      reason: "Santa never enters the basement".to_string(),
+    })?;
+
+    Ok(Answer::new("Reached -1 at step", result as i64))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::{self, Cursor};
+
+  fn solve(raw: &str, part: usize) -> i64 {
+    let floors = DayOne.parse(raw).unwrap();
+    let answer = if part == 1 { DayOne.part_one(&floors) } else { DayOne.part_two(&floors) };
+    answer.unwrap().value
+  }
+
+  // Hands out at most `chunk` bytes per read, like a slow pipe would.
+  struct Trickle<R> {
+    inner: R,
+    chunk: usize,
+  }
+
+  impl<R: Read> Read for Trickle<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+      let len = buf.len().min(self.chunk);
+      self.inner.read(&mut buf[..len])
+    }
+  }
+
+  #[test]
+  fn day_one_examples_work() {
+    assert_eq!(0, solve("(())", 1));
+    assert_eq!(3, solve("))(((((", 1));
+    assert_eq!(-3, solve(")())())", 1));
+    assert_eq!(1, solve(")", 2));
+    assert_eq!(5, solve("()())", 2));
+  }
+
+  #[test]
+  fn tracker_records_extremes() {
+    let tracker = FloorTracker::track(Cursor::new("((()))))))(")).unwrap();
+    assert_eq!(-3, tracker.floor());
+    assert_eq!(11, tracker.steps());
+    assert_eq!(Some(7), tracker.first_basement());
+    assert_eq!(-4, tracker.min_floor());
+    assert_eq!(3, tracker.max_floor());
+
+    let tracker = FloorTracker::track(Cursor::new("(((")).unwrap();
+    assert_eq!(None, tracker.first_basement());
+    assert_eq!(0, tracker.min_floor());
+  }
+
+  #[test]
+  fn tracker_streams_across_reads() {
+    let raw = "(()))(\n)(()((\r\n))".repeat(100);
+    let whole = FloorTracker::track(raw.as_bytes()).unwrap();
+    let trickled = FloorTracker::track(Trickle { inner: raw.as_bytes(), chunk: 3 }).unwrap();
+    assert_eq!(whole, trickled);
+  }
+
+  #[test]
+  fn part_two_fails_without_basement() {
+    let floors = DayOne.parse("(()").unwrap();
+    match DayOne.part_two(&floors) {
+      Err(Error::NoAnswer { day: 1, part: 2, .. }) => {},
+      other => panic!("Expected no answer, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn first_step_at_works() {
+    assert_eq!(Some(0), first_step_at("))".as_bytes(), 0).unwrap());
+    assert_eq!(Some(1), first_step_at(")".as_bytes(), -1).unwrap());
+    assert_eq!(Some(2), first_step_at("(()(".as_bytes(), 2).unwrap());
+    assert_eq!(None, first_step_at("(()(".as_bytes(), 3).unwrap());
+    assert_eq!(Some(5), first_step_at("()())".as_bytes(), -1).unwrap());
+  }
+
+  #[test]
+  fn first_step_at_stops_reading_early() {
+    // The bad byte is never read because floor 1 is reached first.
+    assert_eq!(Some(1), first_step_at("(x".as_bytes(), 1).unwrap());
+    assert!(first_step_at("(x".as_bytes(), 2).is_err());
+  }
+
+  #[test]
+  fn steps_at_works() {
+    assert_eq!(vec![0, 2, 4], steps_at("()\n()".as_bytes(), 0).unwrap());
+    assert_eq!(vec![1, 3], steps_at("()\n()".as_bytes(), 1).unwrap());
+    assert!(steps_at("((".as_bytes(), -1).unwrap().is_empty());
+  }
+
+  // xorshift64, enough to shake out the chunked counter without pulling
+  // in a random number crate.
+  struct Random(u64);
+
+  impl Random {
+    fn next(&mut self) -> u64 {
+      self.0 ^= self.0 << 13;
+      self.0 ^= self.0 >> 7;
+      self.0 ^= self.0 << 17;
+      self.0
+    }
+
+    fn bytes(&mut self, len: usize, alphabet: &[u8]) -> Vec<u8> {
+      (0..len).map(|_| alphabet[self.next() as usize % alphabet.len()]).collect()
+    }
+  }
+
+  #[test]
+  fn count_matches_works() {
+    let word = u64::from_ne_bytes(*b"(()\n)(\x80(");
+    assert_eq!(4, count_matches(word, b'('));
+    assert_eq!(2, count_matches(word, b')'));
+    assert_eq!(1, count_matches(word, b'\n'));
+    assert_eq!(1, count_matches(word, 0x80));
+    assert_eq!(0, count_matches(word, b'\r'));
+  }
+
+  #[test]
+  fn count_chunked_matches_scalar_on_random_input() {
+    let mut random = Random(0x2015_1201);
+    // Every byte value, so the chunked comparison sees the awkward ones too.
+    let everything: Vec<u8> = (0..=255).collect();
+
+    for _ in 0..500 {
+      let len = random.next() as usize % 300;
+      let bytes = if random.next() & 1 == 0 {
+        random.bytes(len, b"()()()\n\r")
+      } else {
+        random.bytes(len, &everything)
+      };
+
+      for offset in 0..len.min(8) {
+        assert_eq!(count_scalar(&bytes[offset..]), count_chunked(&bytes[offset..]));
+      }
+    }
+  }
+
+  #[test]
+  fn final_floor_matches_tracker_on_random_input() {
+    let mut random = Random(0x0c0f_fee5);
+
+    for _ in 0..200 {
+      let len = random.next() as usize % 5000;
+      let mut bytes = random.bytes(len, b"(((()))\n");
+      if len > 0 && random.next() & 3 == 0 {
+        let at = random.next() as usize % len;
+        bytes[at] = b'x';
+      }
+
+      match (FloorTracker::track(&bytes[..]), final_floor(&bytes)) {
+        (Ok(tracker), Ok(floor)) => assert_eq!(tracker.floor(), floor),
+        (Err(Error::Parse(expected)), Err(Error::Parse(err))) => assert_eq!(expected, err),
+        other => panic!("Tracker and final_floor disagree: {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  fn day_one_matches_tracker_on_random_input() {
+    let mut random = Random(0x5a17_a5ea);
+
+    for _ in 0..50 {
+      let len = random.next() as usize % 5000;
+      let raw = String::from_utf8(random.bytes(len, b"()\n")).unwrap();
+      let tracker = FloorTracker::track(raw.as_bytes()).unwrap();
+      let floors = DayOne.parse(&raw).unwrap();
+      assert_eq!(Floors { end: tracker.floor(), basement: tracker.first_basement() }, floors);
+    }
+
+    // the whole input is checked, not just the way down to the basement
+    assert!(DayOne.parse(")(x").is_err());
+  }
+
+  #[test]
+  fn trajectory_works() {
+    let stats = trajectory("(()))))((\n(((".as_bytes()).unwrap();
+
+    let histogram: Vec<(i64, usize)> = stats.histogram().iter().map(|(&floor, &steps)| (floor, steps)).collect();
+    assert_eq!(vec![(-3, 1), (-2, 2), (-1, 2), (0, 3), (1, 3), (2, 2)], histogram);
+    assert_eq!(Some(Run { start: 1, len: 3 }), stats.longest_above_ground());
+    assert_eq!(Some(Run { start: 5, len: 5 }), stats.longest_below_ground());
+
+    let stats = trajectory("".as_bytes()).unwrap();
+    assert_eq!(Some(&1), stats.histogram().get(&0));
+    assert_eq!(None, stats.longest_above_ground());
+  }
+
+  #[test]
+  fn export_trajectory_works() {
+    let mut csv = Vec::new();
+    export_trajectory("()\n)".as_bytes(), &mut csv, Export::Csv).unwrap();
+    assert_eq!("step,floor\n0,0\n1,1\n2,0\n3,-1\n", String::from_utf8(csv).unwrap());
+
+    let mut json = Vec::new();
+    export_trajectory(")".as_bytes(), &mut json, Export::Json).unwrap();
+    assert_eq!("[\n{\"step\":0,\"floor\":0},\n{\"step\":1,\"floor\":-1}\n]\n", String::from_utf8(json).unwrap());
+
+    assert!(export_trajectory("(?".as_bytes(), Vec::new(), Export::Csv).is_err());
+  }
+
+  #[test]
+  fn parse_reports_position_of_bad_character() {
+    match DayOne.parse("(()\n()x)") {
+      Err(Error::Parse(err)) => assert_eq!(ParseError::new(1, 2, 3, "x", "expected `(` or `)`"), err),
+      other => panic!("Expected a parse error, got {:?}", other),
+    }
+
+    match DayOne.parse("(é") {
+      Err(Error::Parse(err)) => assert_eq!("\\xc3", err.token),
+      other => panic!("Expected a parse error, got {:?}", other),
+    }
+  }
 }
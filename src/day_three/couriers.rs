@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::iter;
+use error::{Error, Result};
+use super::{Address, Step, Town};
+
+/// How a list of directions is shared out between couriers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dispatch {
+  /// The couriers take one direction each in turn.
+  RoundRobin(usize),
+  /// Each courier takes as many directions in a row as its weight before
+  /// handing over to the next.
+  Weighted(Vec<usize>),
+  /// Every courier follows its own instructions, one stream each.
+  Streams,
+}
+
+impl Dispatch {
+  /// Hands out `streams` to couriers. Round robin and weighted dispatch
+  /// read the streams one after another as a single list of directions.
+  pub fn split<D: Step>(&self, streams: &[Vec<D>]) -> Result<Vec<Vec<D>>> {
+    match *self {
+      Dispatch::RoundRobin(couriers) => round_robin(streams, couriers),
+      Dispatch::Weighted(ref weights) => take_turns(streams, weights),
+      Dispatch::Streams if streams.is_empty() => Err(Error::Invalid("no couriers to dispatch".to_string())),
+      Dispatch::Streams => Ok(streams.to_vec()),
+    }
+  }
+}
+
+// Couriers beyond the number of directions never leave the origin, so
+// they get empty routes that don't allocate.
+fn round_robin<D: Step>(streams: &[Vec<D>], couriers: usize) -> Result<Vec<Vec<D>>> {
+  let total: usize = streams.iter().map(Vec::len).sum();
+  if couriers == 0 {
+    return Err(Error::Invalid("no couriers to dispatch".to_string()));
+  }
+
+  let mut split = Vec::new();
+  split.try_reserve_exact(couriers)
+  .map_err(|_| Error::Invalid(format!("too many couriers to dispatch: {}", couriers)))?;
+  split.resize_with(couriers.min(total), || Vec::with_capacity(total / couriers + 1));
+  split.resize_with(couriers, Vec::new);
+
+  for (i, dir) in streams.iter().flatten().enumerate() {
+    split[i % couriers].push(*dir);
+  }
+  Ok(split)
+}
+
+fn take_turns<D: Step>(streams: &[Vec<D>], weights: &[usize]) -> Result<Vec<Vec<D>>> {
+  if weights.is_empty() {
+    return Err(Error::Invalid("no couriers to dispatch".to_string()));
+  }
+  if weights.contains(&0) {
+    return Err(Error::Invalid("every courier needs a weight of at least one".to_string()));
+  }
+
+  let total: usize = streams.iter().map(Vec::len).sum();
+  let mut split = vec![Vec::with_capacity(total / weights.len() + 1); weights.len()];
+  let mut turns = weights.iter().enumerate()
+  .cycle()
+  .flat_map(|(courier, &weight)| iter::repeat_n(courier, weight));
+
+  for dir in streams.iter().flatten() {
+    let courier = turns.next().expect("turns never run out");
+    split[courier].push(*dir);
+  }
+  Ok(split)
+}
+
+/// Presents left by each courier and by all of them together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deliveries {
+  pub couriers: Vec<HashMap<Address, usize>>,
+  pub combined: HashMap<Address, usize>,
+}
+
+/// Sends a courier from the origin along each of `routes` around `town`.
+pub fn deliver<D: Step>(routes: &[Vec<D>], town: &Town) -> Result<Deliveries> {
+  let couriers = routes.iter()
+  .map(|route| town.deliver(route))
+  .collect::<Result<Vec<HashMap<Address, usize>>>>()?;
+
+  let mut combined = HashMap::new();
+  for presents in &couriers {
+    for (&address, &count) in presents {
+      *combined.entry(address).or_insert(0) += count;
+    }
+  }
+
+  Ok(Deliveries { couriers, combined })
+}
+
+/// Splits `streams` between couriers according to `dispatch` and delivers.
+pub fn dispatch<D: Step>(streams: &[Vec<D>], dispatch: &Dispatch, town: &Town) -> Result<Deliveries> {
+  deliver(&dispatch.split(streams)?, town)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_three::{directions, streams, Direction};
+
+  fn houses(deliveries: &Deliveries) -> (Vec<usize>, usize) {
+    (deliveries.couriers.iter().map(HashMap::len).collect(), deliveries.combined.len())
+  }
+
+  #[test]
+  fn round_robin_matches_puzzle() {
+    let route = vec![directions("^v^v^v^v^v").unwrap()];
+    let deliveries = dispatch(&route, &Dispatch::RoundRobin(2), &Town::unbounded()).unwrap();
+
+    assert_eq!((vec![6, 6], 11), houses(&deliveries));
+    assert_eq!(2, deliveries.combined[&(0, 0)]);
+    assert_eq!(2, dispatch(&route, &Dispatch::RoundRobin(1), &Town::unbounded()).unwrap().combined.len());
+  }
+
+  #[test]
+  fn round_robin_keeps_idle_couriers_at_the_origin() {
+    let route = vec![directions("^v").unwrap()];
+    let deliveries = dispatch(&route, &Dispatch::RoundRobin(5), &Town::unbounded()).unwrap();
+
+    assert_eq!((vec![2, 2, 1, 1, 1], 3), houses(&deliveries));
+    assert!(deliveries.couriers[2..].iter().all(|presents| presents[&(0, 0)] == 1));
+    assert_eq!(5, deliveries.combined[&(0, 0)]);
+  }
+
+  #[test]
+  fn weighted_takes_runs_of_directions() {
+    // the first courier goes ^^, ^^ and the second v, v
+    let route = vec![directions("^^v^^v").unwrap()];
+    let deliveries = dispatch(&route, &Dispatch::Weighted(vec![2, 1]), &Town::unbounded()).unwrap();
+
+    assert_eq!((vec![5, 3], 7), houses(&deliveries));
+    assert_eq!(1, deliveries.couriers[1][&(0, -2)]);
+  }
+
+  #[test]
+  fn streams_are_followed_separately() {
+    let routes = streams("^>\nvv\n").unwrap();
+    let deliveries = dispatch(&routes, &Dispatch::Streams, &Town::unbounded()).unwrap();
+
+    assert_eq!((vec![3, 3], 5), houses(&deliveries));
+    // round robin reads both lines as one list instead
+    assert_eq!(vec![2, 3], houses(&dispatch(&routes, &Dispatch::RoundRobin(2), &Town::unbounded()).unwrap()).0);
+  }
+
+  #[test]
+  fn dispatch_needs_couriers() {
+    let route = vec![directions("^v").unwrap()];
+    assert!(dispatch(&route, &Dispatch::RoundRobin(0), &Town::unbounded()).is_err());
+    assert!(dispatch(&route, &Dispatch::RoundRobin(usize::MAX), &Town::unbounded()).is_err());
+    assert!(dispatch(&route, &Dispatch::Weighted(vec![]), &Town::unbounded()).is_err());
+    assert!(dispatch(&route, &Dispatch::Weighted(vec![1, 0]), &Town::unbounded()).is_err());
+    assert!(dispatch::<Direction>(&[], &Dispatch::Streams, &Town::unbounded()).is_err());
+  }
+}
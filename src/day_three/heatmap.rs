@@ -0,0 +1,194 @@
+use std::collections::{HashMap, HashSet};
+use error::{Error, Result};
+use image::{Image, Rgb};
+use super::Address;
+
+/// The most pixels `render` will draw, about 200MB of image.
+pub const MAX_PIXELS: usize = 1 << 26;
+
+const BACKGROUND: Rgb = [0, 0, 0];
+
+// From houses that got a single present up to the busiest house.
+const HEAT: [(f64, Rgb); 3] = [(0.0, [40, 20, 110]), (0.5, [220, 50, 50]), (1.0, [255, 230, 80])];
+
+/// Colors given to couriers' routes, in turn.
+pub const ROUTE_COLORS: [Rgb; 6] = [
+  [0, 220, 255], [80, 255, 80], [255, 0, 200], [255, 140, 0], [60, 100, 255], [255, 255, 255],
+];
+
+/// Draws a delivery map with north at the top, each house a square of
+/// `scale` pixels colored by how many presents it got, on a log scale.
+/// Routes are blended over the houses they pass.
+#[derive(Debug, Clone)]
+pub struct Heatmap<'a> {
+  presents: &'a HashMap<Address, usize>,
+  routes: Vec<&'a [Address]>,
+  scale: usize,
+}
+
+// Where a map's corners are and how big it comes out.
+struct Frame {
+  left: isize,
+  top: isize,
+  width: usize,
+  height: usize,
+  scale: usize,
+}
+
+impl Frame {
+  fn pixel(&self, (x, y): Address) -> (usize, usize) {
+    ((x as i128 - self.left as i128) as usize * self.scale, (self.top as i128 - y as i128) as usize * self.scale)
+  }
+}
+
+fn lerp(a: Rgb, b: Rgb, t: f64) -> Rgb {
+  let channel = |i: usize| (a[i] as f64 + (b[i] as f64 - a[i] as f64) * t).round() as u8;
+  [channel(0), channel(1), channel(2)]
+}
+
+/// The color of a house that got `presents` when the busiest got `most`.
+pub fn heat(presents: usize, most: usize) -> Rgb {
+  let t = if most > 1 { (presents as f64).ln() / (most as f64).ln() } else { 1.0 };
+
+  HEAT.windows(2)
+  .find(|stops| t <= stops[1].0)
+  .map(|stops| lerp(stops[0].1, stops[1].1, (t - stops[0].0) / (stops[1].0 - stops[0].0)))
+  .unwrap_or(HEAT[HEAT.len() - 1].1)
+}
+
+impl<'a> Heatmap<'a> {
+  pub fn new(presents: &'a HashMap<Address, usize>) -> Self {
+    Heatmap { presents, routes: Vec::new(), scale: 1 }
+  }
+
+  /// Overlays a courier's route in the next of `ROUTE_COLORS`.
+  pub fn with_route(mut self, route: &'a [Address]) -> Self {
+    self.routes.push(route);
+    self
+  }
+
+  pub fn with_scale(mut self, scale: usize) -> Self {
+    self.scale = scale;
+    self
+  }
+
+  fn frame(&self) -> Result<Frame> {
+    let mut addresses = self.presents.keys().chain(self.routes.iter().flat_map(|route| route.iter()));
+    let &(x, y) = addresses.next().ok_or_else(|| Error::Invalid("no houses to draw".to_string()))?;
+    let (left, right, bottom, top) = addresses
+    .fold((x, x, y, y), |(left, right, bottom, top), &(x, y)| (left.min(x), right.max(x), bottom.min(y), top.max(y)));
+
+    let span = |low: isize, high: isize| (high as i128 - low as i128 + 1) as u128;
+    let (width, height) = (span(left, right), span(bottom, top));
+    let scale = self.scale as u128;
+    let too_big = || Error::Invalid(format!("a {}x{} map at scale {} is too big to draw", width, height, scale));
+
+    if scale == 0 {
+      return Err(Error::Invalid("the scale must be at least one pixel".to_string()));
+    }
+    match width.checked_mul(height) {
+      Some(houses) if houses <= MAX_PIXELS as u128 / (scale * scale) => {},
+      _ => return Err(too_big()),
+    }
+    Ok(Frame { left, top, width: width as usize, height: height as usize, scale: self.scale })
+  }
+
+  fn fill(&self, image: &mut Image, frame: &Frame, address: Address, color: impl Fn(Rgb) -> Rgb) {
+    let (left, top) = frame.pixel(address);
+    for y in top..top + frame.scale {
+      for x in left..left + frame.scale {
+        let blended = color(image.get(x, y));
+        image.set(x, y, blended);
+      }
+    }
+  }
+
+  pub fn render(&self) -> Result<Image> {
+    let frame = self.frame()?;
+    let mut image = Image::new(frame.width * frame.scale, frame.height * frame.scale, BACKGROUND);
+
+    let most = self.presents.values().cloned().max().unwrap_or(0);
+    for (&address, &presents) in self.presents {
+      self.fill(&mut image, &frame, address, |_| heat(presents, most));
+    }
+
+    for (route, &color) in self.routes.iter().zip(ROUTE_COLORS.iter().cycle()) {
+      let mut drawn = HashSet::with_capacity(route.len());
+      for &address in route.iter() {
+        // a route crossing itself shouldn't wash out its own color
+        if drawn.insert(address) {
+          self.fill(&mut image, &frame, address, |under| lerp(under, color, 0.5));
+        }
+      }
+    }
+    Ok(image)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_three::{count_presents_delivered, directions, route, Direction};
+
+  #[test]
+  fn heat_works() {
+    assert_eq!(HEAT[0].1, heat(1, 4));
+    assert_eq!(HEAT[1].1, heat(2, 4));
+    assert_eq!(HEAT[2].1, heat(4, 4));
+    assert_eq!(HEAT[2].1, heat(1, 1));
+  }
+
+  #[test]
+  fn render_puts_north_up() {
+    // (0,0) gets two presents, (0,1), (1,1) and (1,0) one each
+    let presents = count_presents_delivered(&directions("^>v<").unwrap()).unwrap();
+    let image = Heatmap::new(&presents).render().unwrap();
+
+    assert_eq!((2, 2), (image.width, image.height));
+    assert_eq!(heat(1, 2), image.get(0, 0));
+    assert_eq!(heat(2, 2), image.get(0, 1));
+    assert_eq!(heat(1, 2), image.get(1, 1));
+  }
+
+  #[test]
+  fn render_moves_negative_addresses() {
+    let presents = count_presents_delivered(&directions("<v").unwrap()).unwrap();
+    let image = Heatmap::new(&presents).with_scale(2).render().unwrap();
+
+    assert_eq!((4, 4), (image.width, image.height));
+    assert_eq!(heat(1, 1), image.get(1, 1));
+    assert_eq!(heat(1, 1), image.get(3, 0));
+    assert_eq!(heat(1, 1), image.get(0, 3));
+    assert_eq!(BACKGROUND, image.get(3, 3));
+  }
+
+  #[test]
+  fn render_overlays_routes() {
+    let directions = directions(">>").unwrap();
+    let presents = count_presents_delivered(&directions[..1]).unwrap();
+    let route = route(&directions).unwrap();
+    let image = Heatmap::new(&presents).with_route(&route).render().unwrap();
+
+    // the route reaches a house without presents, widening the map
+    assert_eq!(3, image.width);
+    assert_eq!(lerp(heat(1, 1), ROUTE_COLORS[0], 0.5), image.get(0, 0));
+    assert_eq!(lerp(BACKGROUND, ROUTE_COLORS[0], 0.5), image.get(2, 0));
+  }
+
+  #[test]
+  fn render_rejects_huge_maps() {
+    let mut presents = HashMap::new();
+    presents.insert((isize::MIN, 0), 1);
+    presents.insert((isize::MAX, 0), 1);
+    assert!(Heatmap::new(&presents).render().is_err());
+    presents.insert((isize::MIN, isize::MIN), 1);
+    presents.insert((isize::MAX, isize::MAX), 1);
+    assert!(Heatmap::new(&presents).render().is_err());
+    assert!(Heatmap::new(&HashMap::new()).render().is_err());
+
+    let presents = count_presents_delivered::<Direction>(&[]).unwrap();
+    assert!(Heatmap::new(&presents).with_scale(0).render().is_err());
+    assert!(Heatmap::new(&presents).with_scale(1 << 13).frame().is_ok());
+    assert!(Heatmap::new(&presents).with_scale((1 << 13) + 1).frame().is_err());
+  }
+}
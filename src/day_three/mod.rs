@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::slice;
+use error::Result;
+use solution::{Answer, Solution};
+use self::couriers::Dispatch;
+
+pub use self::data::{Direction, directions, streams};
+pub use self::moves::{Compass, Hex, Step, Symbols};
+pub use self::town::{Edge, Town};
+
+pub mod couriers;
+pub mod heatmap;
+pub mod moves;
+pub mod query;
+pub mod town;
+
+pub type Address = (isize, isize);
+
+/// Moves one house in `dir`, failing rather than overflowing at the edge
+/// of `Address`.
+pub fn move_in_direction<D: Step>(address: Address, dir: &D) -> Result<Address> {
+  Town::unbounded().step(address, dir)
+}
+
+/// Follows `directions` from the origin and counts the presents left at
+/// every house visited, including the starting house.
+pub fn count_presents_delivered<D: Step>(directions: &[D]) -> Result<HashMap<Address, usize>> {
+  Town::unbounded().deliver(directions)
+}
+
+/// Every address visited following `directions` from the origin, in order.
+pub fn route<D: Step>(directions: &[D]) -> Result<Vec<Address>> {
+  Town::unbounded().route(directions)
+}
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+  type Input = Vec<Direction>;
+
+  fn parse(&self, raw: &str) -> Result<Vec<Direction>> {
+    data::directions(raw)
+  }
+
+  fn part_one(&self, directions: &Vec<Direction>) -> Result<Answer> {
+    let presents = count_presents_delivered(directions)?;
+
+    Ok(Answer::new("Number of homes that got a present", presents.len() as i64)
+    .with_units("houses"))
+  }
+
+  fn part_two(&self, directions: &Vec<Direction>) -> Result<Answer> {
+    // Santa and Robo-Santa take turns
+    let deliveries = couriers::dispatch(slice::from_ref(directions), &Dispatch::RoundRobin(2), &Town::unbounded())?;
+
+    Ok(Answer::new("Total houses visited", deliveries.combined.len() as i64)
+    .with_units("houses"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::data::Direction::*;
+  use error::Error;
+
+  #[test]
+  fn count_presents_delivered_empty_works() {
+    let directions: Vec<Direction> = vec![];
+    let presents = count_presents_delivered(&directions).unwrap();
+
+    assert_eq!(1, presents.len());
+    assert_eq!(1, *presents.get(&(0,0)).unwrap());
+  }
+
+  #[test]
+  fn count_presents_delivered_one_works() {
+    let directions = vec![N];
+    let presents = count_presents_delivered(&directions).unwrap();
+
+    assert_eq!(2, presents.len());
+    assert_eq!(1, *presents.get(&(0,0)).unwrap());
+    assert_eq!(1, *presents.get(&(0,1)).unwrap());
+  }
+
+  #[test]
+  fn count_presents_delivered_multiple_unique_works() {
+    let directions = vec![N, E, S, W];
+    let presents = count_presents_delivered(&directions).unwrap();
+
+    assert_eq!(4, presents.len());
+    assert_eq!(2, *presents.get(&(0,0)).unwrap());
+    assert_eq!(1, *presents.get(&(0,1)).unwrap());
+    assert_eq!(1, *presents.get(&(1,1)).unwrap());
+    assert_eq!(1, *presents.get(&(1,0)).unwrap());
+  }
+
+  #[test]
+  fn count_presents_delivered_multiple_repeated_works() {
+    let directions = vec![N, S, N, S, N, S, N, S, N, S, ];
+    let presents = count_presents_delivered(&directions).unwrap();
+
+    assert_eq!(2, presents.len());
+    assert_eq!(6, *presents.get(&(0,0)).unwrap());
+    assert_eq!(5, *presents.get(&(0,1)).unwrap());
+  }
+
+  #[test]
+  fn directions_reports_bad_character() {
+    assert_eq!(4, directions("^>v<\n").unwrap().len());
+
+    match directions("^>\n^^?") {
+      Err(Error::Parse(err)) => assert_eq!((2, 3, "?".to_string()), (err.line, err.column, err.token)),
+      other => panic!("Expected a parse error, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn move_in_direction_works() {
+    let addr = (0, 0);
+    assert_eq!((0, 1), move_in_direction(addr, &N).unwrap());
+    assert_eq!((0, -1), move_in_direction(addr, &S).unwrap());
+    assert_eq!((1, 0), move_in_direction(addr, &E).unwrap());
+    assert_eq!((-1, 0), move_in_direction(addr, &W).unwrap());
+    assert!(move_in_direction((isize::MIN, 0), &W).is_err());
+  }
+}
+
+mod data {
+  use error::Result;
+  use super::Symbols;
+
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub enum Direction {
+    N, S, E, W
+  }
+
+  pub fn directions(raw: &str) -> Result<Vec<Direction>> {
+    Symbols::four_way().directions(raw)
+  }
+
+  /// Reads each line as a separate list of directions.
+  pub fn streams(raw: &str) -> Result<Vec<Vec<Direction>>> {
+    Symbols::four_way().streams(raw)
+  }
+}
@@ -0,0 +1,221 @@
+use error::{Error, ParseError, Result};
+use super::{Address, Direction};
+
+/// A direction in some movement model, which knows where it leads.
+pub trait Step: Copy + 'static {
+  /// Every direction of the model, in the order `Symbols::from_chars`
+  /// expects their symbols.
+  fn all() -> &'static [Self];
+  /// How far one move goes east and north.
+  fn offset(&self) -> Address;
+}
+
+impl Step for Direction {
+  fn all() -> &'static [Self] {
+    use self::Direction::*;
+    &[N, E, S, W]
+  }
+
+  fn offset(&self) -> Address {
+    match *self {
+      Direction::N => (0, 1),
+      Direction::S => (0, -1),
+      Direction::E => (1, 0),
+      Direction::W => (-1, 0),
+    }
+  }
+}
+
+/// The four directions plus the diagonals between them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compass {
+  N, NE, E, SE, S, SW, W, NW
+}
+
+impl Step for Compass {
+  fn all() -> &'static [Self] {
+    use self::Compass::*;
+    &[N, NE, E, SE, S, SW, W, NW]
+  }
+
+  fn offset(&self) -> Address {
+    use self::Compass::*;
+    match *self {
+      N => (0, 1),
+      NE => (1, 1),
+      E => (1, 0),
+      SE => (1, -1),
+      S => (0, -1),
+      SW => (-1, -1),
+      W => (-1, 0),
+      NW => (-1, 1),
+    }
+  }
+}
+
+/// Directions on a grid of flat-topped hexagons.
+///
+/// Houses are addressed by axial coordinates: `x` counts columns to the
+/// east and `y` rises going north, so moving north-east leaves `y` alone
+/// and moving south-east lowers it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hex {
+  N, NE, SE, S, SW, NW
+}
+
+impl Step for Hex {
+  fn all() -> &'static [Self] {
+    use self::Hex::*;
+    &[N, NE, SE, S, SW, NW]
+  }
+
+  fn offset(&self) -> Address {
+    use self::Hex::*;
+    match *self {
+      N => (0, 1),
+      NE => (1, 0),
+      SE => (1, -1),
+      S => (0, -1),
+      SW => (-1, 0),
+      NW => (-1, 1),
+    }
+  }
+}
+
+/// Which character stands for which direction in an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbols<D> {
+  table: Vec<(char, D)>,
+}
+
+impl<D: Step> Symbols<D> {
+  /// Builds a table, making sure no character means two things.
+  pub fn new(table: Vec<(char, D)>) -> Result<Self> {
+    if table.is_empty() {
+      return Err(Error::Invalid("a symbol table needs at least one symbol".to_string()));
+    }
+    for (i, &(symbol, _)) in table.iter().enumerate() {
+      if table[..i].iter().any(|&(earlier, _)| earlier == symbol) {
+        return Err(Error::Invalid(format!("`{}` is given more than one direction", symbol)));
+      }
+    }
+    Ok(Symbols { table })
+  }
+
+  /// Gives the model's directions the symbols in `chars`, in the order of
+  /// `Step::all`.
+  pub fn from_chars(chars: &str) -> Result<Self> {
+    if chars.chars().count() != D::all().len() {
+      return Err(Error::Invalid(format!("expected {} symbols, got `{}`", D::all().len(), chars)));
+    }
+    Symbols::new(chars.chars().zip(D::all().iter().cloned()).collect())
+  }
+
+  fn direction(&self, symbol: char) -> Option<D> {
+    self.table.iter().find(|&&(known, _)| known == symbol).map(|&(_, dir)| dir)
+  }
+
+  fn expected(&self) -> String {
+    let symbols: Vec<String> = self.table.iter().map(|&(symbol, _)| format!("`{}`", symbol)).collect();
+    match symbols.split_last() {
+      Some((last, rest)) if !rest.is_empty() => format!("expected one of {} or {}", rest.join(", "), last),
+      _ => format!("expected {}", symbols.join("")),
+    }
+  }
+
+  pub fn directions(&self, raw: &str) -> Result<Vec<D>> {
+    Ok(self.streams(raw)?.concat())
+  }
+
+  /// Reads each line as a separate list of directions.
+  pub fn streams(&self, raw: &str) -> Result<Vec<Vec<D>>> {
+    let mut streams = Vec::new();
+    for (l, line) in raw.lines().enumerate() {
+      let mut directions = Vec::with_capacity(line.len());
+      for (c, ch) in line.chars().enumerate() {
+        match self.direction(ch) {
+          Some(dir) => directions.push(dir),
+          None => return Err(ParseError::new(3, l + 1, c + 1, &ch.to_string(), &self.expected()).into()),
+        }
+      }
+      streams.push(directions);
+    }
+    Ok(streams)
+  }
+}
+
+impl Symbols<Direction> {
+  /// The puzzle's arrows.
+  pub fn four_way() -> Self {
+    Symbols::from_chars("^>v<").expect("the arrows are distinct")
+  }
+}
+
+impl Symbols<Compass> {
+  /// The digits around 5 on a numeric keypad, 8 pointing north.
+  pub fn eight_way() -> Self {
+    Symbols::from_chars("89632147").expect("the digits are distinct")
+  }
+}
+
+impl Symbols<Hex> {
+  /// The keypad digits for eight way moves, less 6 and 4.
+  pub fn hex() -> Self {
+    Symbols::from_chars("893217").expect("the digits are distinct")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_three::{count_presents_delivered, move_in_direction};
+
+  #[test]
+  fn compass_moves_diagonally() {
+    assert_eq!((1, 1), move_in_direction((0, 0), &Compass::NE).unwrap());
+    assert_eq!((-1, -1), move_in_direction((0, 0), &Compass::SW).unwrap());
+
+    // a diamond through the diagonals and back to the start
+    let directions = Symbols::eight_way().directions("9317").unwrap();
+    let presents = count_presents_delivered(&directions).unwrap();
+    assert_eq!(4, presents.len());
+    assert_eq!(2, presents[&(0, 0)]);
+    assert_eq!(1, presents[&(2, 0)]);
+  }
+
+  #[test]
+  fn hex_moves_go_around_a_hexagon() {
+    let directions = Symbols::hex().directions("893217").unwrap();
+    let presents = count_presents_delivered(&directions).unwrap();
+    assert_eq!(6, presents.len());
+    assert_eq!(2, presents[&(0, 0)]);
+
+    // north-east then south-east is two steps east
+    assert_eq!((2, -1), move_in_direction(move_in_direction((0, 0), &Hex::NE).unwrap(), &Hex::SE).unwrap());
+  }
+
+  #[test]
+  fn symbols_are_configurable() {
+    let wasd = Symbols::<Direction>::from_chars("wdsa").unwrap();
+    assert_eq!(count_presents_delivered(&Symbols::four_way().directions("^>v<").unwrap()).unwrap(),
+      count_presents_delivered(&wasd.directions("wdsa").unwrap()).unwrap());
+
+    let custom = Symbols::new(vec![('u', Compass::N), ('U', Compass::N), ('r', Compass::E)]).unwrap();
+    assert_eq!(4, count_presents_delivered(&custom.directions("uUr").unwrap()).unwrap().len());
+
+    assert!(Symbols::<Direction>::from_chars("wds").is_err());
+    assert!(Symbols::<Direction>::from_chars("wdsw").is_err());
+    assert!(Symbols::<Hex>::new(vec![]).is_err());
+  }
+
+  #[test]
+  fn symbols_report_bad_characters() {
+    match Symbols::hex().directions("89\n6") {
+      Err(Error::Parse(err)) => {
+        assert_eq!((2, 1, "6".to_string()), (err.line, err.column, err.token));
+        assert_eq!("expected one of `8`, `9`, `3`, `2`, `1` or `7`", err.reason);
+      },
+      other => panic!("Expected a parse error, got {:?}", other),
+    }
+  }
+}
@@ -0,0 +1,60 @@
+use std::collections::{BTreeMap, HashMap};
+use super::Address;
+
+/// How many houses got at least `k` presents.
+pub fn at_least(presents: &HashMap<Address, usize>, k: usize) -> usize {
+  presents.values().filter(|&&count| count >= k).count()
+}
+
+/// The house that got the most presents and how many. Among equally busy
+/// houses the one furthest west, then south, wins, so the answer doesn't
+/// depend on the map's ordering.
+pub fn busiest(presents: &HashMap<Address, usize>) -> Option<(Address, usize)> {
+  presents.iter()
+  .map(|(&address, &count)| (address, count))
+  .min_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)))
+}
+
+/// How many houses got each number of presents.
+pub fn histogram(presents: &HashMap<Address, usize>) -> BTreeMap<usize, usize> {
+  let mut histogram = BTreeMap::new();
+  for &count in presents.values() {
+    *histogram.entry(count).or_insert(0) += 1;
+  }
+  histogram
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_three::{count_presents_delivered, directions};
+
+  fn presents(raw: &str) -> HashMap<Address, usize> {
+    count_presents_delivered(&directions(raw).unwrap()).unwrap()
+  }
+
+  #[test]
+  fn at_least_works() {
+    // (0,0) gets 6 presents and (0,1) 5
+    let presents = presents("^v^v^v^v^v");
+    assert_eq!(2, at_least(&presents, 0));
+    assert_eq!(2, at_least(&presents, 5));
+    assert_eq!(1, at_least(&presents, 6));
+    assert_eq!(0, at_least(&presents, 7));
+  }
+
+  #[test]
+  fn busiest_works() {
+    assert_eq!(Some(((0, 0), 6)), busiest(&presents("^v^v^v^v^v")));
+    assert_eq!(Some(((0, 0), 2)), busiest(&presents("^>v<")));
+    // every house gets one, so the westernmost wins
+    assert_eq!(Some(((-2, 0), 1)), busiest(&presents("<<")));
+    assert_eq!(None, busiest(&HashMap::new()));
+  }
+
+  #[test]
+  fn histogram_works() {
+    let histogram = histogram(&presents("^>v<^"));
+    assert_eq!(vec![(1, 2), (2, 2)], histogram.into_iter().collect::<Vec<_>>());
+  }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::fmt;
+use error::{Error, Result};
+use super::{Address, Step};
+
+/// What happens to a courier trying to leave a bounded town.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Edge {
+  /// Refuse the move.
+  Error,
+  /// Stop at the edge, delivering to the last house again.
+  Clamp,
+  /// Come back in on the opposite side, as though the town were a torus.
+  Wrap,
+}
+
+impl Edge {
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "error" => Some(Edge::Error),
+      "clamp" => Some(Edge::Clamp),
+      "wrap" => Some(Edge::Wrap),
+      _ => None,
+    }
+  }
+}
+
+/// The houses couriers can reach.
+///
+/// A bounded town is `width` houses across and `height` houses tall,
+/// centered on the origin where every courier starts: its west edge is at
+/// `x = -(width / 2)` and its south edge at `y = -(height / 2)`. Without
+/// bounds the streets go on until `Address` can't hold the next house.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Town {
+  size: Option<(usize, usize)>,
+  edge: Edge,
+}
+
+impl Default for Town {
+  fn default() -> Self {
+    Town::unbounded()
+  }
+}
+
+impl fmt::Display for Town {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.size {
+      Some((width, height)) => write!(f, "{}x{} town", width, height),
+      None => write!(f, "unbounded town"),
+    }
+  }
+}
+
+// Moves `from` by `offset` along one axis of `size` houses starting at
+// `low`, in i128 so that nothing overflows on the way.
+fn axis(from: isize, offset: isize, low: isize, size: usize, edge: Edge) -> Option<isize> {
+  let (low, size) = (low as i128, size as i128);
+  let to = from as i128 + offset as i128;
+
+  let to = match edge {
+    _ if low <= to && to < low + size => to,
+    Edge::Error => return None,
+    Edge::Clamp => to.clamp(low, low + size - 1),
+    Edge::Wrap => low + (to - low).rem_euclid(size),
+  };
+  Some(to as isize)
+}
+
+impl Town {
+  pub fn unbounded() -> Self {
+    Town { size: None, edge: Edge::Error }
+  }
+
+  pub fn bounded(width: usize, height: usize, edge: Edge) -> Result<Self> {
+    if width == 0 || height == 0 || width > isize::MAX as usize || height > isize::MAX as usize {
+      return Err(Error::Invalid(format!("a town can't be {}x{} houses", width, height)));
+    }
+    Ok(Town { size: Some((width, height)), edge })
+  }
+
+  /// The width and height of a bounded town.
+  pub fn size(&self) -> Option<(usize, usize)> {
+    self.size
+  }
+
+  pub fn edge(&self) -> Edge {
+    self.edge
+  }
+
+  /// Whether `address` is one of the town's houses.
+  pub fn contains(&self, (x, y): Address) -> bool {
+    match self.size {
+      Some((width, height)) => {
+        let (left, bottom) = (-((width / 2) as isize), -((height / 2) as isize));
+        axis(x, 0, left, width, Edge::Error).is_some() && axis(y, 0, bottom, height, Edge::Error).is_some()
+      },
+      None => true,
+    }
+  }
+
+  /// Where a courier at `from` ends up after moving in `dir`.
+  pub fn step<D: Step>(&self, from: Address, dir: &D) -> Result<Address> {
+    let ((x, y), (dx, dy)) = (from, dir.offset());
+
+    match self.size {
+      None => x.checked_add(dx)
+        .and_then(|x| y.checked_add(dy).map(|y| (x, y)))
+        .ok_or_else(|| Error::Overflow(format!("a move from ({}, {})", x, y))),
+      Some((width, height)) => {
+        let (left, bottom) = (-((width / 2) as isize), -((height / 2) as isize));
+        axis(x, dx, left, width, self.edge)
+        .and_then(|x| axis(y, dy, bottom, height, self.edge).map(|y| (x, y)))
+        .ok_or_else(|| Error::Invalid(format!("a move from ({}, {}) leaves the {}", x, y, self)))
+      },
+    }
+  }
+
+  /// Every address visited following `directions` from the origin, in order.
+  pub fn route<D: Step>(&self, directions: &[D]) -> Result<Vec<Address>> {
+    let mut route = Vec::with_capacity(directions.len() + 1);
+    route.push((0, 0));
+    for dir in directions {
+      let next = self.step(route[route.len() - 1], dir)?;
+      route.push(next);
+    }
+    Ok(route)
+  }
+
+  /// Follows `directions` from the origin and counts the presents left at
+  /// every house visited, including the starting house.
+  pub fn deliver<D: Step>(&self, directions: &[D]) -> Result<HashMap<Address, usize>> {
+    let mut presents: HashMap<Address, usize> = HashMap::new();
+    let mut address: Address = (0, 0);
+
+    // deliver one present at the initial location
+    presents.insert(address, 1);
+
+    for dir in directions {
+      address = self.step(address, dir)?;
+      *presents.entry(address).or_insert(0) += 1;
+    }
+    Ok(presents)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_three::{directions, Compass, Direction};
+
+  #[test]
+  fn unbounded_moves_are_checked() {
+    let town = Town::unbounded();
+    assert_eq!((isize::MAX, 0), town.step((isize::MAX - 1, 0), &Direction::E).unwrap());
+    assert!(town.step((isize::MAX, 0), &Direction::E).is_err());
+    assert!(town.step((0, isize::MIN), &Direction::S).is_err());
+    assert!(town.step((isize::MIN, 5), &Compass::NW).is_err());
+  }
+
+  #[test]
+  fn bounded_towns_are_centered() {
+    // x from -2 to 1, y from -1 to 1
+    let town = Town::bounded(4, 3, Edge::Error).unwrap();
+    assert!(town.contains((0, 0)));
+    assert!(town.contains((-2, -1)));
+    assert!(town.contains((1, 1)));
+    assert!(!town.contains((2, 0)));
+    assert!(!town.contains((0, -2)));
+    assert!(Town::bounded(1, 1, Edge::Error).unwrap().contains((0, 0)));
+  }
+
+  #[test]
+  fn edge_error_refuses_to_leave() {
+    let town = Town::bounded(3, 3, Edge::Error).unwrap();
+    assert_eq!(5, town.deliver(&directions(">v<<").unwrap()).unwrap().len());
+    match town.deliver(&directions(">>").unwrap()) {
+      Err(Error::Invalid(reason)) => assert_eq!("a move from (1, 0) leaves the 3x3 town", reason),
+      other => panic!("Expected to leave town, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn edge_clamp_stays_at_the_edge() {
+    let town = Town::bounded(3, 3, Edge::Clamp).unwrap();
+    let presents = town.deliver(&directions(">>>").unwrap()).unwrap();
+    assert_eq!(2, presents.len());
+    assert_eq!(3, presents[&(1, 0)]);
+
+    // only the axis that leaves is held back
+    assert_eq!((1, 1), town.step((1, 0), &Compass::NE).unwrap());
+  }
+
+  #[test]
+  fn edge_wrap_comes_back_around() {
+    let town = Town::bounded(3, 2, Edge::Wrap).unwrap();
+    assert_eq!(vec![(0, 0), (1, 0), (-1, 0), (0, 0)], town.route(&directions(">>>").unwrap()).unwrap());
+    // y runs from -1 to 0
+    assert_eq!((0, -1), town.step((0, 0), &Direction::N).unwrap());
+    assert_eq!((0, 0), town.step((0, -1), &Direction::S).unwrap());
+  }
+
+  #[test]
+  fn bounded_rejects_empty_towns() {
+    assert!(Town::bounded(0, 3, Edge::Wrap).is_err());
+    assert!(Town::bounded(3, 0, Edge::Clamp).is_err());
+    assert_eq!(Some(Edge::Wrap), Edge::from_name("wrap"));
+    assert_eq!(None, Edge::from_name("bounce"));
+  }
+}
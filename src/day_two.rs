@@ -1,1086 +0,0 @@
-use std::cmp::min;
-
-type Dimensions = (usize, usize, usize);
-
-fn bow_ribbon(&(l, w, h): &Dimensions) -> usize {
-  l * w * h
-}
-
-fn wrapping_area(&(l, w, h): &Dimensions) -> usize {
-  let side_1 = l * w;
-  let side_2 = w * h;
-  let side_3 = h * l;
-  let slack = min(min(side_1, side_2), side_3);
-
-  2 * (side_1 + side_2 + side_3) + slack
-}
-
-fn wrapping_ribbon(&(l, w, h): &Dimensions) -> usize {
-  let side_1 = l * w;
-  let side_2 = w * h;
-  let side_3 = h * l;
-
-  if side_1 <= side_2 && side_1 <= side_3 {
-    2 * (l + w)
-  }
-  else if side_2 <= side_1 && side_2 <= side_3 {
-    2 * (w + h)
-  }
-  else {
-    2 * (h + l)
-  }
-}
-
-fn part_1(presents: &Vec<Dimensions>) {
-  let total_paper = presents.iter()
-  .map(|present| wrapping_area(&present))
-  .fold(0, |a, b| a + b);
-
-  println!("Total paper required: {} square feet", total_paper);
-}
-
-fn part_2(presents: &Vec<Dimensions>) {
-  let total_ribbon = presents.iter()
-  .map(|present| wrapping_ribbon(&present) + bow_ribbon(&present))
-  .fold(0, |a, b| {
-    a + b
-  });
-
-  println!("Total ribbon needed: {} feet", total_ribbon);
-}
-
-pub fn main() {
-  let presents = data::create();
-  part_1(&presents);
-  part_2(&presents);
-}
-
-#[cfg(test)]
-mod tests {
-  use super::*;
-
-  #[test]
-  fn wrapping_area_works() {
-    assert_eq!(58, wrapping_area(&(2, 3, 4)));
-    assert_eq!(43, wrapping_area(&(1, 1, 10)));
-  }
-
-  #[test]
-  fn wrapping_ribbon_works() {
-    assert_eq!(10, wrapping_ribbon(&(2, 3, 4)));
-    assert_eq!(4, wrapping_ribbon(&(1, 1, 10)));
-  }
-
-  #[test]
-  fn bow_ribbon_works() {
-    assert_eq!(24, bow_ribbon(&(2, 3, 4)));
-    assert_eq!(10, bow_ribbon(&(1, 1, 10)));
-  }
-}
-
-mod data {
-  pub fn create() -> Vec<super::Dimensions> {
-    vec![
-      (3,11,24),
-      (13,5,19),
-      (1,9,27),
-      (24,8,21),
-      (6,8,17),
-      (19,18,22),
-      (10,9,12),
-      (12,2,5),
-      (26,6,11),
-      (9,23,15),
-      (12,8,17),
-      (13,29,10),
-      (28,18,6),
-      (22,28,26),
-      (1,5,11),
-      (29,26,12),
-      (8,28,29),
-      (27,4,21),
-      (12,7,16),
-      (7,4,23),
-      (15,24,8),
-      (15,14,2),
-      (11,6,29),
-      (28,19,9),
-      (10,3,1),
-      (5,20,13),
-      (10,25,1),
-      (22,17,7),
-      (16,29,3),
-      (18,22,8),
-      (18,11,19),
-      (21,24,20),
-      (4,7,17),
-      (22,27,12),
-      (1,26,6),
-      (5,27,24),
-      (29,21,3),
-      (25,30,2),
-      (21,26,2),
-      (10,24,27),
-      (10,16,28),
-      (18,16,23),
-      (6,5,26),
-      (19,12,20),
-      (6,24,25),
-      (11,20,7),
-      (4,8,5),
-      (2,13,11),
-      (11,17,1),
-      (13,24,6),
-      (22,29,16),
-      (4,24,20),
-      (10,25,10),
-      (12,29,23),
-      (23,27,12),
-      (11,21,9),
-      (13,2,6),
-      (15,30,2),
-      (8,26,24),
-      (24,7,30),
-      (22,22,8),
-      (29,27,8),
-      (28,23,27),
-      (13,16,14),
-      (9,28,20),
-      (21,4,30),
-      (21,20,20),
-      (11,17,30),
-      (9,14,22),
-      (20,2,6),
-      (10,11,14),
-      (1,8,23),
-      (23,19,19),
-      (26,10,13),
-      (21,12,12),
-      (25,7,24),
-      (1,28,17),
-      (20,23,9),
-      (2,24,27),
-      (20,24,29),
-      (1,3,10),
-      (5,20,14),
-      (25,21,3),
-      (15,5,22),
-      (14,17,19),
-      (27,3,18),
-      (29,23,19),
-      (14,21,19),
-      (20,8,3),
-      (22,27,12),
-      (24,15,18),
-      (9,10,19),
-      (29,25,28),
-      (14,22,6),
-      (4,19,28),
-      (4,24,14),
-      (17,19,17),
-      (7,19,29),
-      (28,8,26),
-      (7,20,16),
-      (11,26,29),
-      (2,18,3),
-      (12,7,18),
-      (11,15,21),
-      (24,7,26),
-      (2,22,23),
-      (2,30,5),
-      (1,19,8),
-      (15,29,10),
-      (15,26,22),
-      (20,16,14),
-      (25,29,22),
-      (3,13,19),
-      (1,12,30),
-      (3,15,27),
-      (19,9,11),
-      (30,8,21),
-      (26,12,20),
-      (11,17,19),
-      (17,25,1),
-      (19,24,12),
-      (30,6,20),
-      (11,19,18),
-      (18,15,29),
-      (18,8,9),
-      (25,15,5),
-      (15,6,26),
-      (13,27,19),
-      (23,24,12),
-      (3,15,28),
-      (17,10,10),
-      (15,4,7),
-      (15,27,7),
-      (21,8,11),
-      (9,18,2),
-      (7,20,20),
-      (17,23,12),
-      (2,19,1),
-      (7,26,26),
-      (13,23,8),
-      (10,3,12),
-      (11,1,9),
-      (1,11,19),
-      (25,14,26),
-      (16,10,15),
-      (7,6,11),
-      (8,1,27),
-      (20,28,17),
-      (3,25,9),
-      (30,7,5),
-      (17,17,4),
-      (23,25,27),
-      (23,8,5),
-      (13,11,1),
-      (15,10,21),
-      (22,16,1),
-      (12,15,28),
-      (27,18,26),
-      (25,18,5),
-      (21,3,27),
-      (15,25,5),
-      (29,27,19),
-      (11,10,12),
-      (22,16,21),
-      (11,8,18),
-      (6,10,23),
-      (21,21,2),
-      (13,27,28),
-      (2,5,20),
-      (23,16,20),
-      (1,21,7),
-      (22,2,13),
-      (11,10,4),
-      (7,3,4),
-      (19,2,5),
-      (21,11,1),
-      (7,27,26),
-      (12,4,23),
-      (12,3,15),
-      (25,7,4),
-      (20,7,15),
-      (16,5,11),
-      (1,18,26),
-      (11,27,10),
-      (17,6,24),
-      (19,13,16),
-      (6,3,11),
-      (4,19,18),
-      (16,15,15),
-      (1,11,17),
-      (19,11,29),
-      (18,19,1),
-      (1,25,7),
-      (8,22,14),
-      (15,6,19),
-      (5,30,18),
-      (30,24,22),
-      (11,16,2),
-      (21,29,19),
-      (20,29,11),
-      (27,1,18),
-      (20,5,30),
-      (12,4,28),
-      (3,9,30),
-      (26,20,15),
-      (18,25,18),
-      (20,28,28),
-      (21,5,3),
-      (20,21,25),
-      (19,27,22),
-      (8,27,9),
-      (1,5,15),
-      (30,6,19),
-      (16,5,15),
-      (18,30,21),
-      (4,15,8),
-      (9,3,28),
-      (18,15,27),
-      (25,11,6),
-      (17,22,15),
-      (18,12,18),
-      (14,30,30),
-      (1,7,23),
-      (27,21,12),
-      (15,7,18),
-      (16,17,24),
-      (11,12,19),
-      (18,15,21),
-      (6,18,15),
-      (2,21,4),
-      (12,9,14),
-      (19,7,25),
-      (22,3,1),
-      (29,19,7),
-      (30,25,7),
-      (6,27,27),
-      (5,13,9),
-      (21,4,18),
-      (13,1,16),
-      (11,21,25),
-      (27,20,27),
-      (14,25,9),
-      (23,11,15),
-      (22,10,26),
-      (15,16,4),
-      (14,16,21),
-      (1,1,24),
-      (17,27,3),
-      (25,28,16),
-      (12,2,29),
-      (9,19,28),
-      (12,7,17),
-      (6,9,19),
-      (15,14,24),
-      (25,21,23),
-      (26,27,25),
-      (7,18,13),
-      (15,10,6),
-      (22,28,2),
-      (15,2,14),
-      (3,24,18),
-      (30,22,7),
-      (18,27,17),
-      (29,18,7),
-      (20,2,4),
-      (4,20,26),
-      (23,30,15),
-      (5,7,3),
-      (4,24,12),
-      (24,30,20),
-      (26,18,17),
-      (6,28,3),
-      (29,19,29),
-      (14,10,4),
-      (15,5,23),
-      (12,25,4),
-      (7,15,19),
-      (26,21,19),
-      (18,2,23),
-      (19,20,3),
-      (3,13,9),
-      (29,21,24),
-      (26,13,29),
-      (30,27,4),
-      (20,10,29),
-      (21,18,8),
-      (7,26,10),
-      (29,16,21),
-      (22,5,11),
-      (17,15,2),
-      (7,29,5),
-      (6,18,15),
-      (23,6,14),
-      (10,30,14),
-      (26,6,16),
-      (24,13,25),
-      (17,29,20),
-      (4,27,19),
-      (28,12,11),
-      (23,20,3),
-      (22,6,20),
-      (29,9,19),
-      (10,16,22),
-      (30,26,4),
-      (29,26,11),
-      (2,11,15),
-      (1,3,30),
-      (30,30,29),
-      (9,1,3),
-      (30,13,16),
-      (20,4,5),
-      (23,28,11),
-      (24,27,1),
-      (4,25,10),
-      (9,3,6),
-      (14,4,15),
-      (4,5,25),
-      (27,14,13),
-      (20,30,3),
-      (28,15,25),
-      (5,19,2),
-      (10,24,29),
-      (29,30,18),
-      (30,1,25),
-      (7,7,15),
-      (1,13,16),
-      (23,18,4),
-      (1,28,8),
-      (24,11,8),
-      (22,26,19),
-      (30,30,14),
-      (2,4,13),
-      (27,20,26),
-      (16,20,17),
-      (11,12,13),
-      (28,2,17),
-      (15,26,13),
-      (29,15,25),
-      (30,27,9),
-      (2,6,25),
-      (10,26,19),
-      (16,8,23),
-      (12,17,18),
-      (26,14,22),
-      (13,17,4),
-      (27,27,29),
-      (17,13,22),
-      (9,8,3),
-      (25,15,20),
-      (14,13,16),
-      (8,7,13),
-      (12,4,21),
-      (27,16,15),
-      (6,14,5),
-      (28,29,17),
-      (23,17,25),
-      (10,27,28),
-      (1,28,21),
-      (18,2,30),
-      (25,30,16),
-      (25,21,7),
-      (2,3,4),
-      (9,6,13),
-      (19,6,10),
-      (28,17,8),
-      (13,24,28),
-      (24,12,7),
-      (5,19,5),
-      (18,10,27),
-      (16,1,6),
-      (12,14,30),
-      (1,2,28),
-      (23,21,2),
-      (13,3,23),
-      (9,22,10),
-      (10,17,2),
-      (24,20,11),
-      (30,6,14),
-      (28,1,16),
-      (24,20,1),
-      (28,7,7),
-      (1,24,21),
-      (14,9,7),
-      (22,8,15),
-      (20,1,21),
-      (6,3,7),
-      (7,26,14),
-      (5,7,28),
-      (5,4,4),
-      (15,7,28),
-      (30,16,23),
-      (7,26,2),
-      (1,2,30),
-      (24,28,20),
-      (5,17,28),
-      (4,15,20),
-      (15,26,2),
-      (1,3,23),
-      (22,30,24),
-      (9,20,16),
-      (7,15,2),
-      (6,21,18),
-      (21,21,29),
-      (29,10,10),
-      (4,3,23),
-      (23,2,18),
-      (29,24,14),
-      (29,29,16),
-      (22,28,24),
-      (21,18,24),
-      (16,21,6),
-      (3,9,22),
-      (9,18,4),
-      (22,9,9),
-      (12,9,13),
-      (18,21,14),
-      (7,8,29),
-      (28,28,14),
-      (1,6,24),
-      (11,11,3),
-      (8,28,6),
-      (11,16,10),
-      (9,16,16),
-      (6,6,19),
-      (21,5,12),
-      (15,17,12),
-      (3,6,29),
-      (19,1,26),
-      (10,30,25),
-      (24,26,21),
-      (1,10,18),
-      (6,1,16),
-      (4,17,27),
-      (17,11,27),
-      (15,15,21),
-      (14,23,1),
-      (8,9,30),
-      (22,22,25),
-      (20,27,22),
-      (12,7,9),
-      (9,26,19),
-      (26,25,12),
-      (8,8,16),
-      (28,15,10),
-      (29,18,2),
-      (25,22,6),
-      (4,6,15),
-      (12,18,4),
-      (10,3,20),
-      (17,28,17),
-      (14,25,13),
-      (14,10,3),
-      (14,5,10),
-      (7,7,22),
-      (21,2,14),
-      (1,21,5),
-      (27,29,1),
-      (6,20,4),
-      (7,19,23),
-      (28,19,27),
-      (3,9,18),
-      (13,17,17),
-      (18,8,15),
-      (26,23,17),
-      (10,10,13),
-      (11,5,21),
-      (25,15,29),
-      (6,23,24),
-      (10,7,2),
-      (19,10,30),
-      (4,3,23),
-      (22,12,6),
-      (11,17,16),
-      (6,8,12),
-      (18,20,11),
-      (6,2,2),
-      (17,4,11),
-      (20,23,22),
-      (29,23,24),
-      (25,11,21),
-      (22,11,15),
-      (29,3,9),
-      (13,30,5),
-      (17,10,12),
-      (10,30,8),
-      (21,16,17),
-      (1,5,26),
-      (22,15,16),
-      (27,7,11),
-      (16,8,18),
-      (29,9,7),
-      (25,4,17),
-      (10,21,25),
-      (2,19,21),
-      (29,11,16),
-      (18,26,21),
-      (2,8,20),
-      (17,29,27),
-      (25,27,4),
-      (14,3,14),
-      (25,29,29),
-      (26,18,11),
-      (8,24,28),
-      (7,30,24),
-      (12,30,22),
-      (29,20,6),
-      (3,17,1),
-      (6,15,14),
-      (6,22,20),
-      (13,26,26),
-      (12,2,1),
-      (7,14,12),
-      (15,16,11),
-      (3,21,4),
-      (30,17,29),
-      (9,18,27),
-      (11,28,16),
-      (22,3,25),
-      (18,15,15),
-      (2,30,12),
-      (3,27,22),
-      (10,8,8),
-      (26,16,14),
-      (15,2,29),
-      (12,10,7),
-      (21,20,15),
-      (2,15,25),
-      (4,14,13),
-      (3,15,13),
-      (29,8,3),
-      (7,7,28),
-      (15,10,24),
-      (23,15,5),
-      (5,7,14),
-      (24,1,22),
-      (1,11,13),
-      (26,4,19),
-      (19,16,26),
-      (5,25,5),
-      (17,25,14),
-      (23,7,14),
-      (24,6,17),
-      (5,13,12),
-      (20,20,5),
-      (22,29,17),
-      (11,17,29),
-      (25,6,4),
-      (29,8,16),
-      (28,22,24),
-      (24,23,17),
-      (16,17,4),
-      (17,8,25),
-      (22,9,13),
-      (24,4,8),
-      (18,10,20),
-      (21,23,21),
-      (13,14,12),
-      (23,26,4),
-      (4,10,29),
-      (2,18,18),
-      (19,5,21),
-      (2,27,23),
-      (6,29,30),
-      (21,9,20),
-      (6,5,16),
-      (25,10,27),
-      (5,29,21),
-      (24,14,19),
-      (19,11,8),
-      (2,28,6),
-      (19,25,6),
-      (27,1,11),
-      (6,8,29),
-      (18,25,30),
-      (4,27,26),
-      (8,12,1),
-      (7,17,25),
-      (7,14,27),
-      (12,9,5),
-      (14,29,13),
-      (18,17,5),
-      (23,1,3),
-      (28,5,13),
-      (3,2,26),
-      (3,7,11),
-      (1,8,7),
-      (12,5,4),
-      (2,30,21),
-      (16,30,11),
-      (3,26,4),
-      (16,9,4),
-      (11,9,22),
-      (23,5,6),
-      (13,20,3),
-      (4,3,2),
-      (14,10,29),
-      (11,8,12),
-      (26,15,16),
-      (7,17,29),
-      (18,19,18),
-      (8,28,4),
-      (22,6,13),
-      (9,23,7),
-      (11,23,20),
-      (13,11,26),
-      (15,30,13),
-      (1,5,8),
-      (5,10,24),
-      (22,25,17),
-      (27,20,25),
-      (30,10,21),
-      (16,28,24),
-      (20,12,8),
-      (17,25,1),
-      (30,14,9),
-      (14,18,6),
-      (8,28,29),
-      (12,18,29),
-      (9,7,18),
-      (6,12,25),
-      (20,13,24),
-      (22,3,12),
-      (5,23,22),
-      (8,10,17),
-      (7,23,5),
-      (10,26,27),
-      (14,26,19),
-      (10,18,24),
-      (8,4,4),
-      (16,15,11),
-      (3,14,9),
-      (18,5,30),
-      (29,12,26),
-      (16,13,12),
-      (15,10,7),
-      (18,5,26),
-      (14,1,6),
-      (10,8,29),
-      (3,4,9),
-      (19,4,23),
-      (28,17,23),
-      (30,7,17),
-      (19,5,9),
-      (26,29,28),
-      (22,13,17),
-      (28,2,1),
-      (20,30,8),
-      (15,13,21),
-      (25,23,19),
-      (27,23,1),
-      (4,6,23),
-      (29,29,24),
-      (5,18,7),
-      (4,6,30),
-      (17,15,2),
-      (27,4,2),
-      (25,24,14),
-      (28,8,30),
-      (24,29,5),
-      (14,30,14),
-      (10,18,19),
-      (15,26,22),
-      (24,19,21),
-      (29,23,27),
-      (21,10,16),
-      (7,4,29),
-      (14,21,3),
-      (21,4,28),
-      (17,16,15),
-      (24,7,13),
-      (21,24,15),
-      (25,11,16),
-      (10,26,13),
-      (23,20,14),
-      (20,29,27),
-      (14,24,14),
-      (14,23,12),
-      (18,6,5),
-      (3,18,9),
-      (8,18,19),
-      (20,26,15),
-      (16,14,13),
-      (30,16,3),
-      (17,13,4),
-      (15,19,30),
-      (20,3,8),
-      (13,4,5),
-      (12,10,15),
-      (8,23,26),
-      (16,8,15),
-      (22,8,11),
-      (12,11,18),
-      (28,3,30),
-      (15,8,4),
-      (13,22,13),
-      (21,26,21),
-      (29,1,15),
-      (28,9,5),
-      (27,3,26),
-      (22,19,30),
-      (4,11,22),
-      (21,27,20),
-      (22,26,7),
-      (19,28,20),
-      (24,23,16),
-      (26,12,9),
-      (13,22,9),
-      (5,6,23),
-      (20,7,2),
-      (18,26,30),
-      (3,6,28),
-      (24,18,13),
-      (28,19,16),
-      (25,21,25),
-      (25,19,23),
-      (22,29,10),
-      (29,19,30),
-      (4,7,27),
-      (5,12,28),
-      (8,26,6),
-      (14,14,25),
-      (17,17,2),
-      (5,27,11),
-      (8,2,2),
-      (3,20,24),
-      (26,10,9),
-      (22,28,27),
-      (18,15,20),
-      (12,11,1),
-      (5,14,30),
-      (7,3,16),
-      (2,16,16),
-      (18,20,15),
-      (13,14,29),
-      (1,17,12),
-      (13,5,23),
-      (19,4,10),
-      (25,19,11),
-      (15,17,14),
-      (1,28,27),
-      (11,9,28),
-      (9,10,18),
-      (30,11,22),
-      (21,21,20),
-      (2,1,5),
-      (2,25,1),
-      (7,3,4),
-      (22,15,29),
-      (21,28,15),
-      (12,12,4),
-      (21,30,6),
-      (15,10,7),
-      (10,14,6),
-      (21,26,18),
-      (14,25,6),
-      (9,7,11),
-      (22,3,1),
-      (1,16,27),
-      (1,14,23),
-      (2,13,8),
-      (14,19,11),
-      (21,26,1),
-      (4,28,13),
-      (12,16,20),
-      (21,13,9),
-      (3,4,13),
-      (14,9,8),
-      (21,21,12),
-      (27,10,17),
-      (6,20,6),
-      (28,23,23),
-      (2,28,12),
-      (8,10,10),
-      (3,9,2),
-      (20,3,29),
-      (19,4,16),
-      (29,24,9),
-      (26,20,8),
-      (15,28,26),
-      (18,17,10),
-      (7,22,10),
-      (20,15,9),
-      (6,10,8),
-      (7,26,21),
-      (8,8,16),
-      (15,6,29),
-      (22,30,11),
-      (18,25,8),
-      (6,21,20),
-      (7,23,25),
-      (8,25,26),
-      (11,25,27),
-      (22,18,23),
-      (3,2,14),
-      (16,16,1),
-      (15,13,11),
-      (3,9,25),
-      (29,25,24),
-      (9,15,1),
-      (12,4,1),
-      (23,30,20),
-      (3,1,23),
-      (6,10,29),
-      (28,13,24),
-      (4,19,17),
-      (6,6,25),
-      (27,29,17),
-      (12,13,2),
-      (10,7,13),
-      (14,15,8),
-      (22,2,3),
-      (27,17,19),
-      (23,10,16),
-      (5,9,25),
-      (9,25,14),
-      (11,18,6),
-      (18,10,12),
-      (9,4,15),
-      (7,16,14),
-      (17,24,10),
-      (11,4,6),
-      (12,9,17),
-      (22,18,12),
-      (6,24,24),
-      (6,22,23),
-      (5,17,30),
-      (6,9,5),
-      (17,20,10),
-      (6,8,12),
-      (14,17,13),
-      (29,10,17),
-      (22,4,5),
-      (10,19,30),
-      (22,29,11),
-      (10,12,29),
-      (21,22,26),
-      (16,6,25),
-      (1,26,24),
-      (30,17,16),
-      (27,28,5),
-      (30,13,22),
-      (7,26,12),
-      (11,24,30),
-      (1,17,25),
-      (22,1,3),
-      (29,24,6),
-      (4,8,24),
-      (13,9,20),
-      (8,12,9),
-      (21,25,4),
-      (23,23,28),
-      (5,2,19),
-      (29,3,15),
-      (22,1,14),
-      (3,23,30),
-      (8,25,3),
-      (15,8,14),
-      (30,14,6),
-      (23,27,24),
-      (19,1,2),
-      (10,9,13),
-      (13,8,7),
-      (8,13,22),
-      (5,15,20),
-      (17,14,8),
-      (5,11,20),
-      (5,10,27),
-      (24,17,19),
-      (21,2,3),
-      (15,30,26),
-      (21,19,15),
-      (2,7,23),
-      (13,17,25),
-      (30,15,19),
-      (26,4,10),
-      (2,25,8),
-      (9,9,10),
-      (2,25,8),
-      (19,21,30),
-      (17,26,12),
-      (7,5,10),
-      (2,22,14),
-      (10,17,30),
-      (1,8,5),
-      (23,2,25),
-      (22,29,8),
-      (13,26,1),
-      (26,3,30),
-      (25,17,8),
-      (25,18,26),
-      (26,19,15),
-      (8,28,10),
-      (12,16,29),
-      (30,6,29),
-      (28,19,4),
-      (27,26,18),
-      (15,23,17),
-      (5,21,30),
-      (8,11,13),
-      (2,26,7),
-      (19,9,24),
-      (3,22,23),
-      (6,7,18),
-      (4,26,30),
-      (13,25,20),
-      (17,3,15),
-      (8,20,18),
-      (23,18,23),
-      (28,23,9),
-      (16,3,4),
-      (1,29,14),
-      (20,26,22),
-      (3,2,22),
-      (23,8,17),
-      (19,5,17),
-      (21,18,20),
-      (17,21,8),
-      (30,28,1),
-      (29,19,23),
-      (12,12,11),
-      (24,18,7),
-      (21,18,14),
-      (14,26,25),
-      (9,11,3),
-      (10,7,15),
-      (27,6,28),
-      (14,26,4),
-      (28,4,1),
-      (22,25,29),
-      (6,26,6),
-      (1,3,13),
-      (26,22,12),
-      (6,21,26),
-      (23,4,27),
-      (26,13,24),
-      (5,24,28),
-      (22,16,7),
-      (3,27,24),
-      (19,28,2),
-      (11,13,9),
-      (29,16,22),
-      (30,10,24),
-      (14,14,22),
-      (22,23,16),
-      (14,8,3),
-      (20,5,14),
-      (28,6,13),
-      (3,15,25),
-      (4,12,22),
-      (15,12,25),
-      (10,11,24),
-      (7,7,6),
-      (8,11,9),
-      (21,10,29),
-      (23,28,30),
-      (8,29,26),
-      (16,27,11),
-      (1,10,2),
-      (24,20,16),
-      (7,12,28),
-      (28,8,20),
-      (14,10,30),
-      (1,19,6),
-      (4,12,20),
-      (18,2,7),
-      (24,18,17),
-      (16,11,10),
-      (1,12,22),
-      (30,16,28),
-      (18,12,11),
-      (28,9,8),
-      (23,6,17),
-      (10,3,11),
-      (5,12,8),
-      (22,2,23),
-      (9,19,14),
-      (15,28,13),
-      (27,20,23),
-      (19,16,12),
-      (19,30,15),
-      (8,17,4),
-      (10,22,18),
-      (13,22,4),
-      (3,12,19),
-      (22,16,23),
-      (11,8,19),
-      (8,11,6),
-      (7,14,7),
-      (29,17,29),
-      (21,8,12),
-      (21,9,11),
-      (20,1,27),
-      (1,22,11),
-      (5,28,4),
-      (26,7,26),
-      (30,12,18),
-      (29,11,20),
-      (3,12,15),
-      (24,25,17),
-      (14,6,11),
-    ]
-  }
-}
@@ -0,0 +1,171 @@
+use error::{Error, Result};
+use super::{bow_ribbon, wrapping_area, wrapping_ribbon, Dimensions};
+
+/// A roll of wrapping paper, measured in feet and priced in cents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaperRoll {
+  pub width: usize,
+  pub length: usize,
+  pub price: u64,
+}
+
+/// A roll of ribbon, measured in feet and priced in cents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RibbonRoll {
+  pub length: usize,
+  pub price: u64,
+}
+
+/// How many rolls of one kind to buy and what they cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Purchase {
+  pub rolls: usize,
+  /// The rolls the summed total alone would suggest, as if offcuts could
+  /// be spliced together.
+  pub minimum_rolls: usize,
+  /// Feet of roll left over once every piece has been cut.
+  pub waste: usize,
+  pub cost: u64,
+}
+
+/// What wrapping a whole manifest comes to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+  pub paper: Purchase,
+  pub ribbon: Purchase,
+  pub cost: u64,
+}
+
+// Packs the pieces into rolls first-fit decreasing: the longest pieces are
+// cut first, each from the first roll with enough left. Pieces longer than
+// a roll use up whole rolls before their remainder is packed.
+fn purchase(pieces: &[usize], roll: usize, price: u64) -> Result<Purchase> {
+  let mut whole = 0;
+  let mut rest = Vec::with_capacity(pieces.len());
+  for &piece in pieces {
+    whole += piece / roll;
+    if piece % roll > 0 {
+      rest.push(piece % roll);
+    }
+  }
+  rest.sort_unstable_by(|a, b| b.cmp(a));
+
+  let mut left: Vec<usize> = Vec::new();
+  for piece in rest {
+    match left.iter_mut().find(|left| **left >= piece) {
+      Some(left) => *left -= piece,
+      None => left.push(roll - piece),
+    }
+  }
+
+  let total = pieces.iter().map(|&piece| piece as u128).sum::<u128>();
+  let rolls = whole + left.len();
+  let cost = (rolls as u64).checked_mul(price)
+  .ok_or_else(|| Error::Overflow("cost".to_string()))?;
+
+  Ok(Purchase {
+    rolls,
+    minimum_rolls: total.div_ceil(roll as u128) as usize,
+    waste: left.iter().sum(),
+    cost,
+  })
+}
+
+/// Works out the rolls of paper and ribbon to buy for `presents`.
+///
+/// Each present's paper is one piece cut across the full width of a roll,
+/// long enough to cover its wrapping area. Its wrapping ribbon and its bow
+/// are cut as two separate pieces.
+pub fn estimate(presents: &[Dimensions], paper: &PaperRoll, ribbon: &RibbonRoll) -> Result<Estimate> {
+  if paper.width == 0 || paper.length == 0 || ribbon.length == 0 {
+    return Err(Error::Invalid("rolls must be at least a foot in every direction".to_string()));
+  }
+
+  let mut sheets = Vec::with_capacity(presents.len());
+  let mut ribbons = Vec::with_capacity(presents.len() * 2);
+  for present in presents {
+    sheets.push(wrapping_area(present)?.div_ceil(paper.width));
+    ribbons.push(wrapping_ribbon(present)?);
+    ribbons.push(bow_ribbon(present)?);
+  }
+
+  let paper = purchase(&sheets, paper.length, paper.price)?;
+  let ribbon = purchase(&ribbons, ribbon.length, ribbon.price)?;
+  let cost = paper.cost.checked_add(ribbon.cost)
+  .ok_or_else(|| Error::Overflow("cost".to_string()))?;
+
+  Ok(Estimate { paper, ribbon, cost })
+}
+
+/// Reads a price such as `12.99` or `5` into cents.
+pub fn parse_price(price: &str) -> Option<u64> {
+  let mut parts = price.splitn(2, '.');
+  let whole = parts.next()?;
+  let cents = parts.next().unwrap_or("");
+
+  if whole.is_empty() || cents.len() > 2 || !whole.chars().chain(cents.chars()).all(|ch| ch.is_ascii_digit()) {
+    return None;
+  }
+  let cents = format!("{:0<2}", cents).parse::<u64>().ok()?;
+  whole.parse::<u64>().ok()?.checked_mul(100)?.checked_add(cents)
+}
+
+/// Formats cents as a price, the inverse of `parse_price`.
+pub fn format_price(cents: u64) -> String {
+  format!("{}.{:02}", cents / 100, cents % 100)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn purchase_cuts_pieces_from_rolls() {
+    // 7 + 3 and 6 + 4 fit two rolls of 10 exactly, but 6 + 6 + 6 + 2 don't
+    let purchase_of = |pieces: &[usize]| purchase(pieces, 10, 250).unwrap();
+    assert_eq!(Purchase { rolls: 2, minimum_rolls: 2, waste: 0, cost: 500 }, purchase_of(&[3, 6, 7, 4]));
+    assert_eq!(Purchase { rolls: 3, minimum_rolls: 2, waste: 10, cost: 750 }, purchase_of(&[6, 6, 2, 6]));
+    assert_eq!(Purchase { rolls: 0, minimum_rolls: 0, waste: 0, cost: 0 }, purchase_of(&[]));
+  }
+
+  #[test]
+  fn purchase_spans_rolls_for_long_pieces() {
+    let purchase = purchase(&[25, 5], 10, 100).unwrap();
+    assert_eq!(Purchase { rolls: 3, minimum_rolls: 3, waste: 0, cost: 300 }, purchase);
+  }
+
+  #[test]
+  fn estimate_works() {
+    // 58 and 43 square feet of paper on a 4 foot wide roll are cuts of 15
+    // and 11 feet, which don't share a 20 foot roll
+    let paper = PaperRoll { width: 4, length: 20, price: 1299 };
+    let ribbon = RibbonRoll { length: 30, price: 450 };
+    let estimate = estimate(&[(2, 3, 4), (1, 1, 10)], &paper, &ribbon).unwrap();
+
+    assert_eq!(Purchase { rolls: 2, minimum_rolls: 2, waste: 14, cost: 2598 }, estimate.paper);
+    // ribbon pieces of 10, 24, 4 and 10 feet
+    assert_eq!(Purchase { rolls: 2, minimum_rolls: 2, waste: 12, cost: 900 }, estimate.ribbon);
+    assert_eq!(3498, estimate.cost);
+  }
+
+  #[test]
+  fn estimate_rejects_empty_rolls() {
+    let paper = PaperRoll { width: 0, length: 20, price: 1299 };
+    let ribbon = RibbonRoll { length: 30, price: 450 };
+    assert!(estimate(&[(2, 3, 4)], &paper, &ribbon).is_err());
+  }
+
+  #[test]
+  fn prices_work() {
+    assert_eq!(Some(1299), parse_price("12.99"));
+    assert_eq!(Some(1250), parse_price("12.5"));
+    assert_eq!(Some(500), parse_price("5"));
+    assert_eq!(Some(5), parse_price("0.05"));
+    assert_eq!(None, parse_price("12.999"));
+    assert_eq!(None, parse_price(".5"));
+    assert_eq!(None, parse_price("-1"));
+    assert_eq!(None, parse_price("1.x"));
+    assert_eq!("12.05", format_price(1205));
+    assert_eq!("0.00", format_price(0));
+  }
+}
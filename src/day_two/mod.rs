@@ -0,0 +1,323 @@
+use std::convert::TryFrom;
+use std::fmt;
+use error::{Error, Result};
+use solution::{Answer, Solution};
+
+pub type Dimensions = (usize, usize, usize);
+
+pub use self::data::{parse, parse_fractional};
+
+pub mod cost;
+pub mod packing;
+pub mod report;
+pub mod strategy;
+pub mod units;
+
+/// Unsigned integer types a present can be measured in. Every calculation
+/// on them is checked so that huge boxes report an overflow rather than
+/// silently wrapping.
+pub trait Measure: Copy + Ord + fmt::Display {
+  fn checked_add(self, other: Self) -> Option<Self>;
+  fn checked_mul(self, other: Self) -> Option<Self>;
+  fn two() -> Self;
+  /// Widens the value for totals, which are kept as `u128`.
+  fn widen(self) -> u128;
+}
+
+macro_rules! impl_measure {
+  ($($t:ty)*) => {$(
+    impl Measure for $t {
+      fn checked_add(self, other: Self) -> Option<Self> {
+        <$t>::checked_add(self, other)
+      }
+
+      fn checked_mul(self, other: Self) -> Option<Self> {
+        <$t>::checked_mul(self, other)
+      }
+
+      fn two() -> Self {
+        2
+      }
+
+      fn widen(self) -> u128 {
+        self as u128
+      }
+    }
+  )*}
+}
+
+impl_measure!(u8 u16 u32 u64 u128 usize);
+
+fn overflow<T: Measure>(what: &str, &(l, w, h): &(T, T, T)) -> Error {
+  Error::Overflow(format!("{} for {}x{}x{}", what, l, w, h))
+}
+
+fn checked_surface<T: Measure>(l: T, w: T, h: T) -> Option<T> {
+  l.checked_mul(w)?
+  .checked_add(w.checked_mul(h)?)?
+  .checked_add(h.checked_mul(l)?)?
+  .checked_mul(T::two())
+}
+
+// The smallest side is the one between the two shortest edges.
+fn checked_slack<T: Measure>(l: T, w: T, h: T) -> Option<T> {
+  let mut sides = [l, w, h];
+  sides.sort();
+  sides[0].checked_mul(sides[1])
+}
+
+/// Feet of ribbon needed for the bow, equal to the volume of the present.
+pub fn bow_ribbon<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  let (l, w, h) = *present;
+  l.checked_mul(w).and_then(|lw| lw.checked_mul(h))
+  .ok_or_else(|| overflow("bow ribbon", present))
+}
+
+/// Square feet of paper needed to cover every side of a present.
+pub fn surface_area<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  let (l, w, h) = *present;
+  checked_surface(l, w, h).ok_or_else(|| overflow("surface area", present))
+}
+
+/// Square feet of extra paper, equal to the area of the smallest side.
+pub fn slack<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  let (l, w, h) = *present;
+  checked_slack(l, w, h).ok_or_else(|| overflow("slack", present))
+}
+
+/// Square feet of paper needed to wrap a present, plus slack equal to
+/// the area of its smallest side.
+pub fn wrapping_area<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  let (l, w, h) = *present;
+  checked_surface(l, w, h)
+  .and_then(|surface| surface.checked_add(checked_slack(l, w, h)?))
+  .ok_or_else(|| overflow("wrapping area", present))
+}
+
+/// Feet of ribbon needed to wrap the smallest perimeter of a present.
+pub fn wrapping_ribbon<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  let (l, w, h) = *present;
+  let mut sides = [l, w, h];
+  sides.sort();
+
+  sides[0].checked_add(sides[1]).and_then(|half| half.checked_mul(T::two()))
+  .ok_or_else(|| overflow("wrapping ribbon", present))
+}
+
+/// Feet of ribbon for both wrapping and bow.
+pub fn ribbon<T: Measure>(present: &(T, T, T)) -> Result<T> {
+  wrapping_ribbon(present)?.checked_add(bow_ribbon(present)?)
+  .ok_or_else(|| overflow("ribbon", present))
+}
+
+/// Adds up `amount` over every present. The total is a `u128` so that it
+/// can outgrow the type the presents are measured in.
+pub fn total<T, F>(presents: &[(T, T, T)], amount: F) -> Result<u128>
+where T: Measure, F: Fn(&(T, T, T)) -> Result<T> {
+  presents.iter().try_fold(0u128, |total, present| {
+    total.checked_add(amount(present)?.widen())
+    .ok_or_else(|| Error::Overflow("total".to_string()))
+  })
+}
+
+fn answer_value(total: u128) -> Result<i64> {
+  i64::try_from(total).map_err(|_| Error::Overflow(format!("answer {}", total)))
+}
+
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+  type Input = Vec<Dimensions>;
+
+  fn parse(&self, raw: &str) -> Result<Vec<Dimensions>> {
+    data::parse(raw)
+  }
+
+  fn part_one(&self, presents: &Vec<Dimensions>) -> Result<Answer> {
+    let total_paper = total(presents, wrapping_area)?;
+
+    Ok(Answer::new("Total paper required", answer_value(total_paper)?)
+    .with_units("square feet"))
+  }
+
+  fn part_two(&self, presents: &Vec<Dimensions>) -> Result<Answer> {
+    let total_ribbon = total(presents, ribbon)?;
+
+    Ok(Answer::new("Total ribbon needed", answer_value(total_ribbon)?)
+    .with_units("feet"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use error::Error;
+
+  #[test]
+  fn wrapping_area_works() {
+    assert_eq!(58, wrapping_area(&(2usize, 3, 4)).unwrap());
+    assert_eq!(43, wrapping_area(&(1u8, 1, 10)).unwrap());
+  }
+
+  #[test]
+  fn wrapping_ribbon_works() {
+    assert_eq!(10, wrapping_ribbon(&(2usize, 3, 4)).unwrap());
+    assert_eq!(4, wrapping_ribbon(&(1u8, 1, 10)).unwrap());
+    assert_eq!(14, wrapping_ribbon(&(4u32, 9, 3)).unwrap());
+  }
+
+  #[test]
+  fn arithmetic_reports_overflow() {
+    assert_eq!(250, bow_ribbon(&(5u8, 5, 10)).unwrap());
+    assert!(bow_ribbon(&(5u8, 6, 10)).is_err());
+    assert_eq!(205, wrapping_area(&(10u8, 5, 3)).unwrap());
+    assert!(wrapping_area(&(10u8, 7, 3)).is_err());
+    assert!(wrapping_ribbon(&(200u8, 100, 255)).is_err());
+
+    match wrapping_area(&(1, usize::MAX, 2)) {
+      Err(Error::Overflow(what)) => assert_eq!(format!("wrapping area for 1x{}x2", usize::MAX), what),
+      other => panic!("Expected an overflow, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn total_outgrows_dimension_type() {
+    let presents = vec![(u64::MAX, 1, 1); 4];
+    assert_eq!(4 * u64::MAX as u128, total(&presents, bow_ribbon).unwrap());
+    assert!(total(&[(u128::MAX, 1, 1), (1, 1, 1)], bow_ribbon).is_err());
+  }
+
+  #[test]
+  fn parse_works() {
+    assert_eq!(vec![(2, 3, 4), (1, 1, 10)], parse("2x3x4\n\n 1x1x10\n").unwrap());
+  }
+
+  #[test]
+  fn parse_reports_bad_dimensions() {
+    let error = |raw| match parse(raw) {
+      Err(Error::Parse(err)) => (err.line, err.column, err.token),
+      other => panic!("Expected a parse error, got {:?}", other),
+    };
+
+    assert_eq!((2, 3, "y".to_string()), error("2x3x4\n1xyx10"));
+    assert_eq!((1, 7, "4".to_string()), error("2x3x4 4"));
+    assert_eq!((3, 3, "-1".to_string()), error("1x1x1\n\n2x-1x4"));
+  }
+
+  #[test]
+  fn parse_reports_zero_dimensions() {
+    match parse("2x3x4\n2x0x4") {
+      Err(Error::Parse(err)) => {
+        assert_eq!((2, 3, "0".to_string()), (err.line, err.column, err.token));
+        assert_eq!("dimensions must be at least one foot", err.reason);
+      },
+      other => panic!("Expected a parse error, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn parse_fractional_works() {
+    assert_eq!(vec![(2.5, 3.0, 0.25)], parse_fractional(" 2.5x3x.25\n").unwrap());
+
+    let reason = |raw| match parse_fractional(raw) {
+      Err(Error::Parse(err)) => (err.column, err.reason),
+      other => panic!("Expected a parse error, got {:?}", other),
+    };
+    assert_eq!((3, "dimensions must be greater than zero".to_string()), reason("2x0.0x1"));
+    assert_eq!((3, "expected a finite number".to_string()), reason("1xinfx1"));
+    assert_eq!((5, "expected a number".to_string()), reason("1x2xthree"));
+    assert_eq!((5, "missing dimension, expected LxWxH".to_string()), reason("1.5x"));
+  }
+
+  #[test]
+  fn parse_reports_missing_dimensions() {
+    let error = |raw| match parse(raw) {
+      Err(Error::Parse(err)) => (err.line, err.column, err.token, err.reason),
+      other => panic!("Expected a parse error, got {:?}", other),
+    };
+    let missing = "missing dimension, expected LxWxH".to_string();
+
+    assert_eq!((1, 4, String::new(), missing.clone()), error("2x3"));
+    assert_eq!((1, 5, String::new(), missing.clone()), error("2x3x"));
+    assert_eq!((1, 3, "x".to_string(), missing.clone()), error("2xx4"));
+    assert_eq!((2, 3, "x".to_string(), missing), error("1x1x1\n  x3x4"));
+    assert_eq!((1, 6, "x5".to_string(), "expected exactly three dimensions".to_string()), error("2x3x4x5"));
+    assert_eq!((1, 6, "x5x6".to_string(), "expected exactly three dimensions".to_string()), error("2x3x4x5x6"));
+  }
+
+  #[test]
+  fn bow_ribbon_works() {
+    assert_eq!(24, bow_ribbon(&(2usize, 3, 4)).unwrap());
+    assert_eq!(10, bow_ribbon(&(1u16, 1, 10)).unwrap());
+  }
+}
+
+mod data {
+  use std::result;
+  use super::Dimensions;
+  use error::{ParseError, Result};
+  use input::tokens;
+
+  // Splits a line into its three sides, leaving it to `side` to turn each
+  // one into a length or explain why it can't.
+  fn parse_line<T, F>(line_no: usize, line: &str, parse_side: F) -> Result<(T, T, T)>
+  where T: Copy, F: Fn(&str) -> result::Result<T, &'static str> {
+    let tokens = tokens(line);
+    let error = |column, token: &str, reason| ParseError::new(2, line_no, column, token, reason);
+
+    let (column, token) = tokens[0];
+    if let Some(&(extra, token)) = tokens.get(1) {
+      return Err(error(extra, token, "expected one present per line").into());
+    }
+
+    let mut sides = Vec::with_capacity(3);
+    let mut offset = column;
+    let mut start = 0;
+    for side in token.split('x') {
+      if sides.len() == 3 {
+        // point at the `x` that starts the surplus dimensions
+        return Err(error(offset - 1, &token[start - 1..], "expected exactly three dimensions").into());
+      }
+      if side.is_empty() {
+        let next = if offset - column < token.chars().count() { "x" } else { "" };
+        return Err(error(offset, next, "missing dimension, expected LxWxH").into());
+      }
+
+      let length = parse_side(side).map_err(|reason| error(offset, side, reason))?;
+      sides.push(length);
+      offset += side.chars().count() + 1;
+      start += side.len() + 1;
+    }
+
+    if sides.len() != 3 {
+      return Err(error(offset - 1, "", "missing dimension, expected LxWxH").into());
+    }
+    Ok((sides[0], sides[1], sides[2]))
+  }
+
+  fn parse_lines<T, F>(raw: &str, side: F) -> Result<Vec<(T, T, T)>>
+  where T: Copy, F: Fn(&str) -> result::Result<T, &'static str> {
+    raw.lines().enumerate()
+    .filter(|&(_, line)| !line.trim().is_empty())
+    .map(|(l, line)| parse_line(l + 1, line, &side))
+    .collect()
+  }
+
+  pub fn parse(raw: &str) -> Result<Vec<Dimensions>> {
+    parse_lines(raw, |side| match side.parse::<usize>() {
+      Ok(0) => Err("dimensions must be at least one foot"),
+      Ok(length) => Ok(length),
+      Err(_) => Err("expected a whole number of feet"),
+    })
+  }
+
+  /// Like `parse`, but allows decimal dimensions such as `2.5x3x4`.
+  pub fn parse_fractional(raw: &str) -> Result<Vec<(f64, f64, f64)>> {
+    parse_lines(raw, |side| match side.parse::<f64>() {
+      Ok(length) if !length.is_finite() => Err("expected a finite number"),
+      Ok(length) if length <= 0.0 => Err("dimensions must be greater than zero"),
+      Ok(length) => Ok(length),
+      Err(_) => Err("expected a number"),
+    })
+  }
+}
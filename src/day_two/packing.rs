@@ -0,0 +1,428 @@
+use std::collections::{HashMap, HashSet};
+use error::{Error, Result};
+use super::Dimensions;
+
+/// The most presents `pack_exact` will take on.
+pub const EXACT_LIMIT: usize = 6;
+
+pub type Point = (usize, usize, usize);
+
+/// Where a present sits in its crate, and which way round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Placement {
+  /// Index of the present in the manifest.
+  pub present: usize,
+  /// The corner nearest the crate's origin.
+  pub position: Point,
+  /// The present's dimensions along the crate's axes, after rotating.
+  pub size: Dimensions,
+}
+
+impl Placement {
+  fn contains(&self, (px, py, pz): Point) -> bool {
+    let ((x, y, z), (l, w, h)) = (self.position, self.size);
+    x <= px && px < x + l && y <= py && py < y + w && z <= pz && pz < z + h
+  }
+
+  fn overlaps(&self, other: &Placement) -> bool {
+    let ((x, y, z), (l, w, h)) = (self.position, self.size);
+    let ((ox, oy, oz), (ol, ow, oh)) = (other.position, other.size);
+
+    x < ox + ol && ox < x + l &&
+    y < oy + ow && oy < y + w &&
+    z < oz + oh && oz < z + h
+  }
+}
+
+/// The presents packed into one crate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Crate {
+  pub placements: Vec<Placement>,
+}
+
+/// Presents spread over as few crates as could be managed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing {
+  pub crate_size: Dimensions,
+  pub crates: Vec<Crate>,
+  volume: u128,
+}
+
+impl Packing {
+  pub fn crate_count(&self) -> usize {
+    self.crates.len()
+  }
+
+  /// The share of crate space taken up by presents, from 0 to 1.
+  pub fn fill_ratio(&self) -> f64 {
+    if self.crates.is_empty() {
+      return 0.0;
+    }
+    self.volume as f64 / (crate_volume(self.crate_size) * self.crates.len() as u128) as f64
+  }
+}
+
+fn volume(&(l, w, h): &Dimensions) -> u128 {
+  l as u128 * w as u128 * h as u128
+}
+
+fn crate_volume(size: Dimensions) -> u128 {
+  volume(&size)
+}
+
+// Every distinct way of turning a present to line up with the axes.
+fn rotations(&(l, w, h): &Dimensions) -> Vec<Dimensions> {
+  let mut rotations = vec![(l, w, h), (l, h, w), (w, l, h), (w, h, l), (h, l, w), (h, w, l)];
+  rotations.sort();
+  rotations.dedup();
+  rotations
+}
+
+fn within(position: Point, size: Dimensions, crate_size: Dimensions) -> bool {
+  let ((x, y, z), (l, w, h)) = (position, size);
+  let (cl, cw, ch) = crate_size;
+  l <= cl && w <= cw && h <= ch &&
+  x <= cl - l && y <= cw - w && z <= ch - h
+}
+
+// The origin plus the three corners of every placed present that touch
+// its far faces, lowest first, leaving out any already taken up by another
+// present. Presents are only ever placed at these.
+fn corner_points(placements: &[Placement]) -> Vec<Point> {
+  let mut points = vec![(0, 0, 0)];
+  for placement in placements {
+    let ((x, y, z), (l, w, h)) = (placement.position, placement.size);
+    points.push((x + l, y, z));
+    points.push((x, y + w, z));
+    points.push((x, y, z + h));
+  }
+
+  points.sort_by_key(|&(x, y, z)| (z, y, x));
+  points.dedup();
+  points.retain(|&point| !placements.iter().any(|placement| placement.contains(point)));
+  points
+}
+
+// Every way `present` can go into one of `points`, in the order given.
+fn candidates<'a>(points: &'a [Point], rotations: &'a [Dimensions], placements: &'a [Placement], present: usize, crate_size: Dimensions)
+-> impl Iterator<Item = Placement> + 'a {
+  points.iter()
+  .flat_map(move |&position| rotations.iter().map(move |&size| Placement { present, position, size }))
+  .filter(move |placement| {
+    within(placement.position, placement.size, crate_size) &&
+    !placements.iter().any(|other| other.overlaps(placement))
+  })
+}
+
+fn check(presents: &[Dimensions], crate_size: Dimensions) -> Result<u128> {
+  let (l, w, h) = crate_size;
+  if l == 0 || w == 0 || h == 0 {
+    return Err(Error::Invalid("crates must be at least a foot in every direction".to_string()));
+  }
+  crate_size.0.checked_mul(crate_size.1).and_then(|area| area.checked_mul(crate_size.2))
+  .ok_or_else(|| Error::Overflow(format!("volume of {}x{}x{} crate", l, w, h)))?;
+
+  let mut total = 0;
+  for (i, present) in presents.iter().enumerate() {
+    if !rotations(present).into_iter().any(|size| within((0, 0, 0), size, crate_size)) {
+      let (pl, pw, ph) = *present;
+      return Err(Error::Invalid(format!("present {} ({}x{}x{}) does not fit in a {}x{}x{} crate", i + 1, pl, pw, ph, l, w, h)));
+    }
+    total += volume(present);
+  }
+  Ok(total)
+}
+
+// Biggest presents first, keeping manifest order among equals.
+fn largest_first(presents: &[Dimensions]) -> Vec<usize> {
+  let mut order: Vec<usize> = (0..presents.len()).collect();
+  order.sort_by(|&a, &b| volume(&presents[b]).cmp(&volume(&presents[a])));
+  order
+}
+
+// How far `reach` along each axis from `point` stays clear of `placement`.
+fn clearance(point: Point, reach: Dimensions, placement: &Placement) -> Dimensions {
+  let ((px, py, pz), (rx, ry, rz)) = (point, reach);
+  let ((x, y, z), (l, w, h)) = (placement.position, placement.size);
+  let (in_x, in_y, in_z) = (x <= px && px < x + l, y <= py && py < y + w, z <= pz && pz < z + h);
+
+  (
+    if in_y && in_z && x >= px { rx.min(x - px) } else { rx },
+    if in_x && in_z && y >= py { ry.min(y - py) } else { ry },
+    if in_x && in_y && z >= pz { rz.min(z - pz) } else { rz },
+  )
+}
+
+// A crate still being filled by `pack`. Alongside each free corner it keeps
+// how far the corner can see along each axis before hitting a wall or a
+// present, which turns most presents away from a corner they can't go in
+// without checking them against everything already packed.
+struct Open {
+  placements: Vec<Placement>,
+  corners: Vec<(Point, Dimensions)>,
+  free: u128,
+}
+
+impl Open {
+  fn new(crate_size: Dimensions) -> Self {
+    Open { placements: Vec::new(), corners: vec![((0, 0, 0), crate_size)], free: crate_volume(crate_size) }
+  }
+
+  fn first_fit(&self, present: usize, rotations: &[Dimensions]) -> Option<Placement> {
+    self.corners.iter()
+    .flat_map(|&(position, (rx, ry, rz))| {
+      rotations.iter()
+      .filter(move |&&(l, w, h)| l <= rx && w <= ry && h <= rz)
+      .map(move |&size| Placement { present, position, size })
+    })
+    .find(|placement| !self.placements.iter().any(|other| other.overlaps(placement)))
+  }
+
+  fn place(&mut self, placement: Placement, crate_size: Dimensions) {
+    self.corners.retain(|&(point, _)| !placement.contains(point));
+    for corner in &mut self.corners {
+      corner.1 = clearance(corner.0, corner.1, &placement);
+    }
+    self.free -= volume(&placement.size);
+    self.placements.push(placement);
+
+    let ((x, y, z), (l, w, h)) = (placement.position, placement.size);
+    let (cl, cw, ch) = crate_size;
+    for &point in &[(x + l, y, z), (x, y + w, z), (x, y, z + h)] {
+      let (px, py, pz) = point;
+      if px >= cl || py >= cw || pz >= ch || self.corners.iter().any(|&(corner, _)| corner == point) ||
+        self.placements.iter().any(|other| other.contains(point)) {
+        continue;
+      }
+      let reach = self.placements.iter()
+      .fold((cl - px, cw - py, ch - pz), |reach, other| clearance(point, reach, other));
+      self.corners.push((point, reach));
+    }
+    self.corners.sort_by_key(|&((x, y, z), _)| (z, y, x));
+  }
+}
+
+/// Packs presents first-fit decreasing: the biggest presents go first,
+/// each into the first crate with a free corner it fits in.
+pub fn pack(presents: &[Dimensions], crate_size: Dimensions) -> Result<Packing> {
+  let volume_needed = check(presents, crate_size)?;
+  let mut crates: Vec<Open> = Vec::new();
+
+  for i in largest_first(presents) {
+    let rotations = rotations(&presents[i]);
+    let needed = volume(&presents[i]);
+
+    let slot = crates.iter().enumerate()
+    .filter(|&(_, open)| open.free >= needed)
+    .filter_map(|(c, open)| open.first_fit(i, &rotations).map(|placement| (c, placement)))
+    .next();
+
+    match slot {
+      Some((c, placement)) => crates[c].place(placement, crate_size),
+      None => {
+        let mut open = Open::new(crate_size);
+        let placement = open.first_fit(i, &rotations)
+        .expect("`check` makes sure every present fits an empty crate");
+        open.place(placement, crate_size);
+        crates.push(open);
+      }
+    }
+  }
+
+  let crates = crates.into_iter().map(|open| Crate { placements: open.placements }).collect();
+  Ok(Packing { crate_size, crates, volume: volume_needed })
+}
+
+// Searches every order, orientation and corner for the presents in `mask`,
+// skipping arrangements already ruled out by another route.
+struct Fitter<'a> {
+  presents: &'a [Dimensions],
+  crate_size: Dimensions,
+  fits: HashMap<u32, Option<Vec<Placement>>>,
+}
+
+impl<'a> Fitter<'a> {
+  fn fit(&mut self, mask: u32) -> Option<Vec<Placement>> {
+    if let Some(fit) = self.fits.get(&mask) {
+      return fit.clone();
+    }
+
+    let mut seen = HashSet::new();
+    let mut placements = Vec::new();
+    let fit = if self.search(mask, &mut placements, &mut seen) { Some(placements) } else { None };
+    self.fits.insert(mask, fit.clone());
+    fit
+  }
+
+  fn search(&self, left: u32, placements: &mut Vec<Placement>, seen: &mut HashSet<Vec<Placement>>) -> bool {
+    if left == 0 {
+      return true;
+    }
+
+    let mut key = placements.clone();
+    key.sort();
+    if !seen.insert(key) {
+      return false;
+    }
+
+    for i in (0..self.presents.len()).filter(|&i| left & (1 << i) != 0) {
+      let points = corner_points(placements);
+      let rotations = rotations(&self.presents[i]);
+      let options: Vec<Placement> = candidates(&points, &rotations, placements, i, self.crate_size).collect();
+      for placement in options {
+        placements.push(placement);
+        if self.search(left & !(1 << i), placements, seen) {
+          return true;
+        }
+        placements.pop();
+      }
+    }
+    false
+  }
+}
+
+// Tries to share the presents in `order` between at most `crates` crates.
+fn assign(fitter: &mut Fitter, order: &[usize], crates: usize, groups: &mut Vec<u32>) -> bool {
+  let (&present, rest) = match order.split_first() {
+    Some(split) => split,
+    None => return true,
+  };
+
+  for g in 0..groups.len() {
+    let group = groups[g] | 1 << present;
+    if fitter.fit(group).is_some() {
+      groups[g] = group;
+      if assign(fitter, rest, crates, groups) {
+        return true;
+      }
+      groups[g] &= !(1 << present);
+    }
+  }
+
+  // an empty crate is the same whichever one it is, so only try one
+  if groups.len() < crates {
+    groups.push(1 << present);
+    if assign(fitter, rest, crates, groups) {
+      return true;
+    }
+    groups.pop();
+  }
+  false
+}
+
+/// Finds the fewest crates for up to `EXACT_LIMIT` presents by trying
+/// every way of sharing them out, falling back to `pack` only when nothing
+/// beats it.
+///
+/// A crate's presents count as fitting when some order, orientation and
+/// choice of corner points stacks them all in, so the search is exact over
+/// the same kind of arrangement `pack` builds.
+pub fn pack_exact(presents: &[Dimensions], crate_size: Dimensions) -> Result<Packing> {
+  if presents.len() > EXACT_LIMIT {
+    return Err(Error::Invalid(format!("exact packing is limited to {} presents, not {}", EXACT_LIMIT, presents.len())));
+  }
+
+  let heuristic = pack(presents, crate_size)?;
+  let lower_bound = heuristic.volume.div_ceil(crate_volume(crate_size)) as usize;
+
+  let order = largest_first(presents);
+  let mut fitter = Fitter { presents, crate_size, fits: HashMap::new() };
+  for crates in lower_bound..heuristic.crate_count() {
+    let mut groups = Vec::new();
+    if assign(&mut fitter, &order, crates, &mut groups) {
+      let crates = groups.into_iter()
+      .map(|group| Crate { placements: fitter.fit(group).unwrap_or_default() })
+      .collect();
+      return Ok(Packing { crate_size, crates, volume: heuristic.volume });
+    }
+  }
+  Ok(heuristic)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_valid(packing: &Packing, presents: &[Dimensions]) {
+    let mut packed: Vec<usize> = packing.crates.iter()
+    .flat_map(|packed| packed.placements.iter().map(|placement| placement.present))
+    .collect();
+    packed.sort();
+    assert_eq!((0..presents.len()).collect::<Vec<_>>(), packed);
+
+    for packed in &packing.crates {
+      for (i, placement) in packed.placements.iter().enumerate() {
+        assert!(within(placement.position, placement.size, packing.crate_size));
+        assert!(rotations(&presents[placement.present]).contains(&placement.size));
+        assert!(packed.placements[i + 1..].iter().all(|other| !other.overlaps(placement)));
+      }
+    }
+  }
+
+  #[test]
+  fn rotations_works() {
+    assert_eq!(6, rotations(&(1, 2, 3)).len());
+    assert_eq!(3, rotations(&(1, 1, 2)).len());
+    assert_eq!(vec![(2, 2, 2)], rotations(&(2, 2, 2)));
+  }
+
+  #[test]
+  fn pack_fills_crates() {
+    let presents = vec![(1, 1, 1); 8];
+    let packing = pack(&presents, (2, 2, 2)).unwrap();
+    assert_valid(&packing, &presents);
+    assert_eq!(1, packing.crate_count());
+    assert_eq!(1.0, packing.fill_ratio());
+  }
+
+  #[test]
+  fn pack_rotates_presents() {
+    // neither fits lying along the crate's width
+    let presents = vec![(1, 4, 1), (1, 1, 4)];
+    let packing = pack(&presents, (4, 2, 1)).unwrap();
+    assert_valid(&packing, &presents);
+    assert_eq!(1, packing.crate_count());
+  }
+
+  #[test]
+  fn pack_opens_new_crates() {
+    let presents = vec![(2, 3, 4), (1, 1, 10), (3, 3, 3), (4, 2, 3)];
+    let packing = pack(&presents, (5, 5, 10)).unwrap();
+    assert_valid(&packing, &presents);
+    assert_eq!(1, packing.crate_count());
+
+    let presents = vec![(2, 3, 4), (3, 3, 3), (4, 2, 3)];
+    let packing = pack(&presents, (4, 4, 4)).unwrap();
+    assert_valid(&packing, &presents);
+    assert_eq!(2, packing.crate_count());
+  }
+
+  #[test]
+  fn pack_rejects_presents_too_big() {
+    match pack(&[(2, 3, 4), (1, 1, 10)], (4, 4, 4)) {
+      Err(Error::Invalid(reason)) => assert_eq!("present 2 (1x1x10) does not fit in a 4x4x4 crate", reason),
+      other => panic!("Expected an invalid packing, got {:?}", other),
+    }
+    assert!(pack(&[(1, 1, 1)], (0, 1, 1)).is_err());
+  }
+
+  #[test]
+  fn pack_exact_beats_heuristic() {
+    // Largest first lays the 2x2 present flat, after which the 3x1 one
+    // only fits in a second crate; standing both on end fits everything.
+    let presents = vec![(2, 2, 1), (3, 1, 1), (1, 2, 1)];
+    let crate_size = (3, 2, 2);
+
+    assert_eq!(2, pack(&presents, crate_size).unwrap().crate_count());
+    let exact = pack_exact(&presents, crate_size).unwrap();
+    assert_valid(&exact, &presents);
+    assert_eq!(1, exact.crate_count());
+    assert_eq!(0.75, exact.fill_ratio());
+  }
+
+  #[test]
+  fn pack_exact_is_limited() {
+    assert!(pack_exact(&[(1, 1, 1); EXACT_LIMIT + 1], (2, 2, 2)).is_err());
+    assert_eq!(0, pack_exact(&[], (2, 2, 2)).unwrap().crate_count());
+  }
+}
@@ -0,0 +1,188 @@
+use std::convert::TryFrom;
+use std::io::Write;
+use error::{Error, Result};
+use export::{self, Export};
+use json::Object;
+use super::{bow_ribbon, slack, surface_area, wrapping_area, wrapping_ribbon, ribbon, Dimensions};
+
+/// Everything needed to wrap a single present.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Materials {
+  /// Position of the present in the manifest, counting from 1.
+  pub number: usize,
+  pub dimensions: Dimensions,
+  /// Square feet of paper covering the sides, not counting slack.
+  pub paper: usize,
+  pub slack: usize,
+  pub wrap_ribbon: usize,
+  pub bow_ribbon: usize,
+}
+
+impl Materials {
+  pub fn new(number: usize, dimensions: &Dimensions) -> Result<Self> {
+    // make sure the sums below can't overflow either
+    wrapping_area(dimensions)?;
+    ribbon(dimensions)?;
+
+    Ok(Materials {
+      number,
+      dimensions: *dimensions,
+      paper: surface_area(dimensions)?,
+      slack: slack(dimensions)?,
+      wrap_ribbon: wrapping_ribbon(dimensions)?,
+      bow_ribbon: bow_ribbon(dimensions)?,
+    })
+  }
+
+  /// Square feet of paper including slack.
+  pub fn total_paper(&self) -> usize {
+    self.paper + self.slack
+  }
+
+  /// Feet of ribbon for both wrapping and bow.
+  pub fn total_ribbon(&self) -> usize {
+    self.wrap_ribbon + self.bow_ribbon
+  }
+}
+
+/// Materials summed over a whole manifest.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Totals {
+  pub paper: u128,
+  pub slack: u128,
+  pub wrap_ribbon: u128,
+  pub bow_ribbon: u128,
+}
+
+impl Totals {
+  fn add(&mut self, materials: &Materials) -> Result<()> {
+    fn add(total: &mut u128, amount: usize, what: &str) -> Result<()> {
+      *total = total.checked_add(amount as u128)
+      .ok_or_else(|| Error::Overflow(format!("total {}", what)))?;
+      Ok(())
+    }
+
+    add(&mut self.paper, materials.paper, "paper")?;
+    add(&mut self.slack, materials.slack, "slack")?;
+    add(&mut self.wrap_ribbon, materials.wrap_ribbon, "wrap ribbon")?;
+    add(&mut self.bow_ribbon, materials.bow_ribbon, "bow ribbon")
+  }
+}
+
+/// A per-present breakdown of the paper and ribbon a manifest needs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BillOfMaterials {
+  pub presents: Vec<Materials>,
+  pub totals: Totals,
+}
+
+fn json_int(value: u128) -> Result<i64> {
+  i64::try_from(value).map_err(|_| Error::Overflow(format!("exported value {}", value)))
+}
+
+impl BillOfMaterials {
+  pub fn new(presents: &[Dimensions]) -> Result<Self> {
+    let mut bill = BillOfMaterials { presents: Vec::with_capacity(presents.len()), totals: Totals::default() };
+
+    for (i, dimensions) in presents.iter().enumerate() {
+      let materials = Materials::new(i + 1, dimensions)?;
+      bill.totals.add(&materials)?;
+      bill.presents.push(materials);
+    }
+    Ok(bill)
+  }
+
+  /// The `n` presents needing the most paper, with ribbon breaking ties and
+  /// earlier presents coming first among equals.
+  pub fn most_expensive(&self, n: usize) -> Vec<&Materials> {
+    let mut presents: Vec<&Materials> = self.presents.iter().collect();
+    presents.sort_by(|a, b| {
+      (b.total_paper(), b.total_ribbon()).cmp(&(a.total_paper(), a.total_ribbon()))
+      .then(a.number.cmp(&b.number))
+    });
+    presents.truncate(n);
+    presents
+  }
+
+  /// Writes a row per present followed by a `total` row.
+  pub fn export<W: Write>(&self, out: W, format: Export) -> Result<()> {
+    let mut rows = Vec::with_capacity(self.presents.len() + 1);
+
+    for materials in &self.presents {
+      let (l, w, h) = materials.dimensions;
+      rows.push(Object::new()
+        .field("present", materials.number.to_string())
+        .field("length", l)
+        .field("width", w)
+        .field("height", h)
+        .field("paper", materials.paper)
+        .field("slack", materials.slack)
+        .field("wrap_ribbon", materials.wrap_ribbon)
+        .field("bow_ribbon", materials.bow_ribbon));
+    }
+
+    let totals = &self.totals;
+    rows.push(Object::new()
+      .field("present", "total")
+      .field("length", None::<usize>)
+      .field("width", None::<usize>)
+      .field("height", None::<usize>)
+      .field("paper", json_int(totals.paper)?)
+      .field("slack", json_int(totals.slack)?)
+      .field("wrap_ribbon", json_int(totals.wrap_ribbon)?)
+      .field("bow_ribbon", json_int(totals.bow_ribbon)?));
+
+    export::write_rows(out, format, rows)?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn bill() -> BillOfMaterials {
+    BillOfMaterials::new(&[(2, 3, 4), (1, 1, 10), (4, 3, 2), (3, 3, 3)]).unwrap()
+  }
+
+  #[test]
+  fn materials_works() {
+    let materials = Materials::new(1, &(2, 3, 4)).unwrap();
+    assert_eq!((52, 6, 10, 24), (materials.paper, materials.slack, materials.wrap_ribbon, materials.bow_ribbon));
+    assert_eq!(58, materials.total_paper());
+    assert_eq!(34, materials.total_ribbon());
+
+    assert!(Materials::new(1, &(usize::MAX, 2, 1)).is_err());
+  }
+
+  #[test]
+  fn totals_works() {
+    let totals = bill().totals;
+    assert_eq!(Totals { paper: 52 + 42 + 52 + 54, slack: 6 + 1 + 6 + 9, wrap_ribbon: 10 + 4 + 10 + 12, bow_ribbon: 24 + 10 + 24 + 27 }, totals);
+  }
+
+  #[test]
+  fn most_expensive_works() {
+    let bill = bill();
+    let numbers: Vec<usize> = bill.most_expensive(3).iter().map(|materials| materials.number).collect();
+    assert_eq!(vec![4, 1, 3], numbers);
+    assert_eq!(4, bill.most_expensive(10).len());
+  }
+
+  #[test]
+  fn export_works() {
+    let bill = BillOfMaterials::new(&[(2, 3, 4)]).unwrap();
+
+    let mut csv = Vec::new();
+    bill.export(&mut csv, Export::Csv).unwrap();
+    assert_eq!("present,length,width,height,paper,slack,wrap_ribbon,bow_ribbon\n\
+                1,2,3,4,52,6,10,24\n\
+                total,,,,52,6,10,24\n", String::from_utf8(csv).unwrap());
+
+    let mut json = Vec::new();
+    bill.export(&mut json, Export::Json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.starts_with("[\n{\"present\":\"1\",\"length\":2,"));
+    assert!(json.ends_with("{\"present\":\"total\",\"length\":null,\"width\":null,\"height\":null,\"paper\":52,\"slack\":6,\"wrap_ribbon\":10,\"bow_ribbon\":24}\n]\n"));
+  }
+}
@@ -0,0 +1,198 @@
+use error::{Error, Result};
+use super::{bow_ribbon, ribbon, surface_area, wrapping_area, Dimensions};
+
+/// What wrapping one present takes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Wrapping {
+  /// Square feet of paper.
+  pub paper: usize,
+  /// Feet of ribbon.
+  pub ribbon: usize,
+  pub bags: usize,
+}
+
+/// Materials summed over a manifest.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Totals {
+  pub paper: u128,
+  pub ribbon: u128,
+  pub bags: u128,
+}
+
+impl Totals {
+  fn add(&mut self, wrapping: &Wrapping) -> Result<()> {
+    let add = |total: u128, amount: usize| total.checked_add(amount as u128)
+    .ok_or_else(|| Error::Overflow("total".to_string()));
+
+    self.paper = add(self.paper, wrapping.paper)?;
+    self.ribbon = add(self.ribbon, wrapping.ribbon)?;
+    self.bags = add(self.bags, wrapping.bags)?;
+    Ok(())
+  }
+}
+
+/// A policy for wrapping presents.
+pub trait Strategy {
+  /// A short description for reports.
+  fn name(&self) -> String;
+  fn wrap(&self, present: &Dimensions) -> Result<Wrapping>;
+
+  fn totals(&self, presents: &[Dimensions]) -> Result<Totals> {
+    let mut totals = Totals::default();
+    for present in presents {
+      totals.add(&self.wrap(present)?)?;
+    }
+    Ok(totals)
+  }
+}
+
+fn overflow(what: &str, &(l, w, h): &Dimensions) -> Error {
+  Error::Overflow(format!("{} for {}x{}x{}", what, l, w, h))
+}
+
+/// The elves' rules: slack equal to the smallest side, ribbon around the
+/// smallest perimeter and a bow as long as the present's volume.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SmallestSide;
+
+impl Strategy for SmallestSide {
+  fn name(&self) -> String {
+    "smallest side slack".to_string()
+  }
+
+  fn wrap(&self, present: &Dimensions) -> Result<Wrapping> {
+    Ok(Wrapping { paper: wrapping_area(present)?, ribbon: ribbon(present)?, bags: 0 })
+  }
+}
+
+/// Slack of a fixed percentage of the surface area, rounded up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PercentageSlack {
+  pub percent: usize,
+}
+
+impl Strategy for PercentageSlack {
+  fn name(&self) -> String {
+    format!("{}% slack", self.percent)
+  }
+
+  fn wrap(&self, present: &Dimensions) -> Result<Wrapping> {
+    let surface = surface_area(present)?;
+    let paper = surface.checked_mul(self.percent)
+    .and_then(|slack| surface.checked_add(slack.div_ceil(100)))
+    .ok_or_else(|| overflow("paper", present))?;
+
+    Ok(Wrapping { paper, ribbon: ribbon(present)?, bags: 0 })
+  }
+}
+
+/// No slack, but a strip of paper `margin` feet wide along every edge of
+/// the present so the sheets overlap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlapMargin {
+  pub margin: usize,
+}
+
+impl Strategy for OverlapMargin {
+  fn name(&self) -> String {
+    format!("{} ft overlap", self.margin)
+  }
+
+  fn wrap(&self, present: &Dimensions) -> Result<Wrapping> {
+    let (l, w, h) = *present;
+    let surface = surface_area(present)?;
+    let paper = l.checked_add(w)
+    .and_then(|edges| edges.checked_add(h))
+    .and_then(|edges| edges.checked_mul(4))
+    .and_then(|edges| edges.checked_mul(self.margin))
+    .and_then(|overlap| overlap.checked_add(surface))
+    .ok_or_else(|| overflow("paper", present))?;
+
+    Ok(Wrapping { paper, ribbon: ribbon(present)?, bags: 0 })
+  }
+}
+
+/// Puts presents whose longest edge is more than `ratio` times their
+/// shortest into a gift bag tied with a bow, wrapping the rest with
+/// `paper`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GiftBag<S> {
+  pub ratio: usize,
+  pub paper: S,
+}
+
+impl<S> GiftBag<S> {
+  /// Whether a present is odd enough to go in a bag.
+  pub fn bagged(&self, &(l, w, h): &Dimensions) -> bool {
+    let longest = l.max(w).max(h);
+    let shortest = l.min(w).min(h);
+    longest > shortest.saturating_mul(self.ratio)
+  }
+}
+
+impl<S: Strategy> Strategy for GiftBag<S> {
+  fn name(&self) -> String {
+    format!("bags above {}:1, else {}", self.ratio, self.paper.name())
+  }
+
+  fn wrap(&self, present: &Dimensions) -> Result<Wrapping> {
+    if self.bagged(present) {
+      Ok(Wrapping { paper: 0, ribbon: bow_ribbon(present)?, bags: 1 })
+    } else {
+      self.paper.wrap(present)
+    }
+  }
+}
+
+/// Totals each strategy on the same manifest, in the order given.
+pub fn compare(presents: &[Dimensions], strategies: &[&dyn Strategy]) -> Result<Vec<(String, Totals)>> {
+  strategies.iter()
+  .map(|strategy| strategy.totals(presents).map(|totals| (strategy.name(), totals)))
+  .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn smallest_side_matches_puzzle() {
+    assert_eq!(Wrapping { paper: 58, ribbon: 34, bags: 0 }, SmallestSide.wrap(&(2, 3, 4)).unwrap());
+    assert_eq!(Wrapping { paper: 43, ribbon: 14, bags: 0 }, SmallestSide.wrap(&(1, 1, 10)).unwrap());
+  }
+
+  #[test]
+  fn percentage_slack_rounds_up() {
+    assert_eq!(58, PercentageSlack { percent: 10 }.wrap(&(2, 3, 4)).unwrap().paper);
+    assert_eq!(52, PercentageSlack { percent: 0 }.wrap(&(2, 3, 4)).unwrap().paper);
+    assert!(PercentageSlack { percent: usize::MAX }.wrap(&(2, 3, 4)).is_err());
+  }
+
+  #[test]
+  fn overlap_margin_works() {
+    // 52 square feet of sides and 36 feet of edges
+    assert_eq!(88, OverlapMargin { margin: 1 }.wrap(&(2, 3, 4)).unwrap().paper);
+    assert_eq!(52, OverlapMargin { margin: 0 }.wrap(&(2, 3, 4)).unwrap().paper);
+  }
+
+  #[test]
+  fn gift_bag_takes_odd_shapes() {
+    let strategy = GiftBag { ratio: 4, paper: SmallestSide };
+    assert!(strategy.bagged(&(1, 1, 10)));
+    assert!(!strategy.bagged(&(1, 1, 4)));
+    assert!(!strategy.bagged(&(2, 3, 4)));
+
+    assert_eq!(Wrapping { paper: 0, ribbon: 10, bags: 1 }, strategy.wrap(&(1, 1, 10)).unwrap());
+    assert_eq!(SmallestSide.wrap(&(2, 3, 4)).unwrap(), strategy.wrap(&(2, 3, 4)).unwrap());
+  }
+
+  #[test]
+  fn compare_works() {
+    let presents = [(2, 3, 4), (1, 1, 10)];
+    let bag = GiftBag { ratio: 4, paper: SmallestSide };
+    let results = compare(&presents, &[&SmallestSide, &bag]).unwrap();
+
+    assert_eq!(("smallest side slack".to_string(), Totals { paper: 101, ribbon: 48, bags: 0 }), results[0]);
+    assert_eq!(("bags above 4:1, else smallest side slack".to_string(), Totals { paper: 58, ribbon: 44, bags: 1 }), results[1]);
+  }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+
+/// Units of length a present can be measured in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+  Feet,
+  Inches,
+  Centimetres,
+}
+
+impl Unit {
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "ft" | "feet" => Some(Unit::Feet),
+      "in" | "inches" => Some(Unit::Inches),
+      "cm" | "centimetres" => Some(Unit::Centimetres),
+      _ => None,
+    }
+  }
+
+  fn per_foot(self) -> f64 {
+    match self {
+      Unit::Feet => 1.0,
+      Unit::Inches => 12.0,
+      Unit::Centimetres => 30.48,
+    }
+  }
+}
+
+impl fmt::Display for Unit {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Unit::Feet => write!(f, "ft"),
+      Unit::Inches => write!(f, "in"),
+      Unit::Centimetres => write!(f, "cm"),
+    }
+  }
+}
+
+/// How fractional results are turned into whole units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rounding {
+  /// Keep the fraction.
+  Exact,
+  /// Round up, so there is never too little paper or ribbon.
+  Up,
+  Down,
+  Nearest,
+}
+
+impl Rounding {
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "exact" => Some(Rounding::Exact),
+      "up" => Some(Rounding::Up),
+      "down" => Some(Rounding::Down),
+      "nearest" => Some(Rounding::Nearest),
+      _ => None,
+    }
+  }
+
+  pub fn apply(self, value: f64) -> f64 {
+    // Conversions leave results like 58.000000000001 behind, which
+    // shouldn't round up to 59.
+    let whole = value.round();
+    let value = if (value - whole).abs() < 1e-9 * whole.abs().max(1.0) { whole } else { value };
+
+    match self {
+      Rounding::Exact => value,
+      Rounding::Up => value.ceil(),
+      Rounding::Down => value.floor(),
+      Rounding::Nearest => value.round(),
+    }
+  }
+
+  /// Shows a value rounded by `apply`, keeping at most two decimals of an
+  /// exact one so conversion noise doesn't reach the reader.
+  pub fn format(self, value: f64) -> String {
+    match self {
+      Rounding::Exact => {
+        let fixed = format!("{:.2}", self.apply(value));
+        fixed.trim_end_matches('0').trim_end_matches('.').to_string()
+      },
+      _ => format!("{:.0}", self.apply(value)),
+    }
+  }
+}
+
+/// Works out paper and ribbon for presents measured in `input` units,
+/// reporting the results in `output` units rounded by `rounding`.
+///
+/// The puzzle's formulas are applied in feet, so a present gets the same
+/// paper and ribbon whatever it was measured in. In particular the bow
+/// still takes a foot of ribbon per cubic foot of present.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurer {
+  pub input: Unit,
+  pub output: Unit,
+  pub rounding: Rounding,
+}
+
+impl Default for Measurer {
+  fn default() -> Self {
+    Measurer { input: Unit::Feet, output: Unit::Feet, rounding: Rounding::Exact }
+  }
+}
+
+impl Measurer {
+  pub fn new(input: Unit, output: Unit, rounding: Rounding) -> Self {
+    Measurer { input, output, rounding }
+  }
+
+  fn in_feet(&self, &(l, w, h): &(f64, f64, f64)) -> (f64, f64, f64) {
+    let scale = self.input.per_foot();
+    (l / scale, w / scale, h / scale)
+  }
+
+  fn length(&self, feet: f64) -> f64 {
+    self.rounding.apply(feet * self.output.per_foot())
+  }
+
+  fn area(&self, square_feet: f64) -> f64 {
+    let scale = self.output.per_foot();
+    self.rounding.apply(square_feet * scale * scale)
+  }
+
+  // The smallest distance around a present measured in feet.
+  fn perimeter(&self, present: &(f64, f64, f64)) -> f64 {
+    let (l, w, h) = self.in_feet(present);
+    2.0 * (l + w + h - l.max(w).max(h))
+  }
+
+  /// Paper to wrap a present, plus slack equal to its smallest side, in
+  /// square output units.
+  pub fn wrapping_area(&self, present: &(f64, f64, f64)) -> f64 {
+    let (l, w, h) = self.in_feet(present);
+    let sides = [l * w, w * h, h * l];
+    let slack = sides[0].min(sides[1]).min(sides[2]);
+
+    self.area(2.0 * (sides[0] + sides[1] + sides[2]) + slack)
+  }
+
+  /// Ribbon around the smallest perimeter of a present.
+  pub fn wrapping_ribbon(&self, present: &(f64, f64, f64)) -> f64 {
+    self.length(self.perimeter(present))
+  }
+
+  pub fn bow_ribbon(&self, present: &(f64, f64, f64)) -> f64 {
+    let (l, w, h) = self.in_feet(present);
+    self.length(l * w * h)
+  }
+
+  /// Wrapping and bow ribbon together, rounded once.
+  pub fn ribbon(&self, present: &(f64, f64, f64)) -> f64 {
+    let (l, w, h) = self.in_feet(present);
+    self.length(self.perimeter(present) + l * w * h)
+  }
+
+  /// Total paper for a manifest, rounding each present separately.
+  pub fn total_area(&self, presents: &[(f64, f64, f64)]) -> f64 {
+    presents.iter().map(|present| self.wrapping_area(present)).sum()
+  }
+
+  /// Total ribbon for a manifest, rounding each present separately.
+  pub fn total_ribbon(&self, presents: &[(f64, f64, f64)]) -> f64 {
+    presents.iter().map(|present| self.ribbon(present)).sum()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_two::parse_fractional;
+
+  #[test]
+  fn feet_match_whole_number_formulas() {
+    let measurer = Measurer::default();
+    assert_eq!(58.0, measurer.wrapping_area(&(2.0, 3.0, 4.0)));
+    assert_eq!(10.0, measurer.wrapping_ribbon(&(2.0, 3.0, 4.0)));
+    assert_eq!(24.0, measurer.bow_ribbon(&(2.0, 3.0, 4.0)));
+    assert_eq!(34.0, measurer.ribbon(&(2.0, 3.0, 4.0)));
+  }
+
+  #[test]
+  fn fractional_dimensions_work() {
+    let presents = parse_fractional("2.5x3x4\n0.5x1x1").unwrap();
+    assert_eq!(vec![(2.5, 3.0, 4.0), (0.5, 1.0, 1.0)], presents);
+
+    let exact = Measurer::default();
+    assert_eq!(66.5, exact.wrapping_area(&presents[0]));
+    assert_eq!(4.5, exact.wrapping_area(&presents[1]));
+    assert_eq!(71.0, exact.total_area(&presents));
+
+    let up = Measurer::new(Unit::Feet, Unit::Feet, Rounding::Up);
+    assert_eq!(72.0, up.total_area(&presents));
+    let down = Measurer::new(Unit::Feet, Unit::Feet, Rounding::Down);
+    assert_eq!(70.0, down.total_area(&presents));
+  }
+
+  #[test]
+  fn units_convert_consistently() {
+    let feet = Measurer::default();
+    let inches = Measurer::new(Unit::Inches, Unit::Feet, Rounding::Exact);
+    let centimetres = Measurer::new(Unit::Centimetres, Unit::Feet, Rounding::Up);
+
+    // the same present measured three ways
+    assert_eq!(58.0, feet.wrapping_area(&(2.0, 3.0, 4.0)));
+    assert_eq!(58.0, Rounding::Nearest.apply(inches.wrapping_area(&(24.0, 36.0, 48.0))));
+    assert_eq!(58.0, centimetres.wrapping_area(&(60.96, 91.44, 121.92)));
+    assert_eq!(34.0, centimetres.ribbon(&(60.96, 91.44, 121.92)));
+
+    let to_inches = Measurer::new(Unit::Feet, Unit::Inches, Rounding::Exact);
+    assert_eq!(58.0 * 144.0, to_inches.wrapping_area(&(2.0, 3.0, 4.0)));
+    assert_eq!(34.0 * 12.0, to_inches.ribbon(&(2.0, 3.0, 4.0)));
+  }
+
+  #[test]
+  fn rounding_ignores_conversion_noise() {
+    assert_eq!(58.0, Rounding::Up.apply(58.000000000001));
+    assert_eq!(59.0, Rounding::Up.apply(58.01));
+    assert_eq!(2.0, Rounding::Down.apply(2.9));
+    assert_eq!(3.0, Rounding::Nearest.apply(2.5));
+    assert_eq!(2.25, Rounding::Exact.apply(2.25));
+  }
+
+  #[test]
+  fn names_work() {
+    assert_eq!(Some(Unit::Centimetres), Unit::from_name("cm"));
+    assert_eq!(Some(Unit::Inches), Unit::from_name("inches"));
+    assert_eq!(None, Unit::from_name("yards"));
+    assert_eq!("in", Unit::Inches.to_string());
+    assert_eq!(Some(Rounding::Up), Rounding::from_name("up"));
+    assert_eq!("10246289.18", Rounding::Exact.format(10246289.184800005));
+    assert_eq!("2.5", Rounding::Exact.format(2.5));
+    assert_eq!("58", Rounding::Exact.format(58.000000000001));
+    assert_eq!("59", Rounding::Up.format(58.2));
+    assert_eq!(None, Rounding::from_name("sideways"));
+  }
+}
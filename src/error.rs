@@ -0,0 +1,93 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+/// Describes exactly where a day's input stopped making sense.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+  pub day: usize,
+  pub line: usize,
+  pub column: usize,
+  pub token: String,
+  pub reason: String,
+}
+
+impl ParseError {
+  pub fn new(day: usize, line: usize, column: usize, token: &str, reason: &str) -> Self {
+    ParseError {
+      day, line, column,
+      token: token.to_string(),
+      reason: reason.to_string(),
+    }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "day {}, line {}, column {}: ", self.day, self.line, self.column)?;
+    if self.token.is_empty() {
+      write!(f, "unexpected end of line, {}", self.reason)
+    } else {
+      write!(f, "unexpected `{}`, {}", self.token, self.reason)
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum Error {
+  Io(io::Error),
+  Parse(ParseError),
+  Answers { line: usize, reason: String },
+  Baseline { line: usize, reason: String },
+  NoAnswer { day: usize, part: usize, reason: String },
+  /// A calculation, described by the string, did not fit its integer type.
+  Overflow(String),
+  /// Settings or input that can't be worked with, such as a present too
+  /// big for anything it has to fit into.
+  Invalid(String),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Error::Io(ref err) => write!(f, "{}", err),
+      Error::Parse(ref err) => write!(f, "{}", err),
+      Error::Answers { line, ref reason } => write!(f, "answers, line {}: {}", line, reason),
+      Error::Baseline { line, ref reason } => write!(f, "baseline, line {}: {}", line, reason),
+      Error::NoAnswer { day, part, ref reason } => write!(f, "day {}, part {}: {}", day, part, reason),
+      Error::Overflow(ref what) => write!(f, "{} overflows", what),
+      Error::Invalid(ref reason) => write!(f, "{}", reason),
+    }
+  }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+  fn from(err: io::Error) -> Self {
+    Error::Io(err)
+  }
+}
+
+impl From<ParseError> for Error {
+  fn from(err: ParseError) -> Self {
+    Error::Parse(err)
+  }
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_error_display_works() {
+    let err = ParseError::new(6, 3, 6, "sideways", "expected `on` or `off`");
+    assert_eq!("day 6, line 3, column 6: unexpected `sideways`, expected `on` or `off`", err.to_string());
+
+    let err = ParseError::new(6, 1, 9, "", "expected a coordinate");
+    assert_eq!("day 6, line 1, column 9: unexpected end of line, expected a coordinate", err.to_string());
+  }
+}
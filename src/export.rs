@@ -0,0 +1,84 @@
+use std::io::{self, Write};
+use json::{Object, Value};
+
+/// The formats reports can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Export {
+  /// A header row followed by one row per record.
+  Csv,
+  /// An array of objects, one per line.
+  Json,
+}
+
+fn write_cell<W: Write>(out: &mut W, value: &Value) -> io::Result<()> {
+  match *value {
+    Value::Null => Ok(()),
+    Value::Str(ref value) if value.contains([',', '"', '\n']) => {
+      write!(out, "\"{}\"", value.replace('"', "\"\""))
+    },
+    Value::Str(ref value) => write!(out, "{}", value),
+    ref value => write!(out, "{}", value),
+  }
+}
+
+/// Writes `rows` to `out` in `format`. Every row should have the same fields
+/// in the same order, as the CSV header is taken from the first.
+pub fn write_rows<W, I>(mut out: W, format: Export, rows: I) -> io::Result<()>
+where W: Write, I: IntoIterator<Item = Object> {
+  let mut empty = true;
+
+  for row in rows {
+    match format {
+      Export::Csv => {
+        if empty {
+          let header: Vec<&str> = row.fields().iter().map(|&(key, _)| key).collect();
+          writeln!(out, "{}", header.join(","))?;
+        }
+        for (i, (_, value)) in row.fields().iter().enumerate() {
+          if i > 0 {
+            write!(out, ",")?;
+          }
+          write_cell(&mut out, value)?;
+        }
+        writeln!(out)?;
+      },
+      Export::Json => write!(out, "{}\n{}", if empty { "[" } else { "," }, row)?,
+    }
+    empty = false;
+  }
+
+  if format == Export::Json {
+    writeln!(out, "{}]", if empty { "[" } else { "\n" })?;
+  }
+  out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rows() -> Vec<Object> {
+    vec![
+      Object::new().field("name", "a, b").field("size", 3usize),
+      Object::new().field("name", "c").field("size", None::<usize>),
+    ]
+  }
+
+  #[test]
+  fn write_csv_works() {
+    let mut out = Vec::new();
+    write_rows(&mut out, Export::Csv, rows()).unwrap();
+    assert_eq!("name,size\n\"a, b\",3\nc,\n", String::from_utf8(out).unwrap());
+  }
+
+  #[test]
+  fn write_json_works() {
+    let mut out = Vec::new();
+    write_rows(&mut out, Export::Json, rows()).unwrap();
+    assert_eq!("[\n{\"name\":\"a, b\",\"size\":3},\n{\"name\":\"c\",\"size\":null}\n]\n", String::from_utf8(out).unwrap());
+
+    let mut out = Vec::new();
+    write_rows(&mut out, Export::Json, vec![]).unwrap();
+    assert_eq!("[]\n", String::from_utf8(out).unwrap());
+  }
+}
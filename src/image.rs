@@ -0,0 +1,261 @@
+use std::convert::TryFrom;
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+/// An RGB image, stored row by row from the top left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+  pub width: usize,
+  pub height: usize,
+  pub pixels: Vec<Rgb>,
+}
+
+/// File formats an image can be written in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFormat {
+  Ppm,
+  Png,
+}
+
+impl ImageFormat {
+  /// Picks the format from a file name's extension.
+  pub fn from_file_name(name: &str) -> Option<Self> {
+    let extension = name[name.rfind('.')? + 1..].to_ascii_lowercase();
+    match extension.as_str() {
+      "ppm" => Some(ImageFormat::Ppm),
+      "png" => Some(ImageFormat::Png),
+      _ => None,
+    }
+  }
+}
+
+// Stored deflate blocks can't hold any more than this.
+const MAX_STORED: usize = 65535;
+
+impl Image {
+  pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+    Image { width, height, pixels: vec![background; width * height] }
+  }
+
+  pub fn get(&self, x: usize, y: usize) -> Rgb {
+    self.pixels[y * self.width + x]
+  }
+
+  pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+    self.pixels[y * self.width + x] = color;
+  }
+
+  pub fn write<W: Write>(&self, out: W, format: ImageFormat) -> io::Result<()> {
+    match format {
+      ImageFormat::Ppm => self.write_ppm(out),
+      ImageFormat::Png => self.write_png(out),
+    }
+  }
+
+  /// Writes a binary PPM (P6).
+  pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+    for pixel in &self.pixels {
+      out.write_all(pixel)?;
+    }
+    out.flush()
+  }
+
+  /// Writes an 8 bit RGB PNG. The pixels aren't compressed, just wrapped in
+  /// stored deflate blocks, which keeps the encoder short.
+  pub fn write_png<W: Write>(&self, mut out: W) -> io::Result<()> {
+    let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "image is too big for a PNG");
+    let width = u32::try_from(self.width).map_err(|_| too_big())?;
+    let height = u32::try_from(self.height).map_err(|_| too_big())?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // bit depth 8, truecolour, deflate, no filtering, no interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    // every row starts with filter type 0, none
+    let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+    for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+      raw.push(0);
+      for pixel in row {
+        raw.extend_from_slice(pixel);
+      }
+    }
+
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    write_chunk(&mut out, b"IHDR", &header)?;
+    write_chunk(&mut out, b"IDAT", &zlib_stored(&raw))?;
+    write_chunk(&mut out, b"IEND", &[])?;
+    out.flush()
+  }
+}
+
+fn write_chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+  let length = u32::try_from(data.len())
+  .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image is too big for a PNG"))?;
+
+  let mut crc = Crc32::new();
+  crc.update(kind);
+  crc.update(data);
+
+  out.write_all(&length.to_be_bytes())?;
+  out.write_all(kind)?;
+  out.write_all(data)?;
+  out.write_all(&crc.finish().to_be_bytes())
+}
+
+// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+  let blocks = data.len().div_ceil(MAX_STORED).max(1);
+  let mut stream = Vec::with_capacity(data.len() + blocks * 5 + 6);
+
+  // deflate with a 32K window, no preset dictionary, checksum divisible by 31
+  stream.extend_from_slice(&[0x78, 0x01]);
+
+  let mut chunks = data.chunks(MAX_STORED).peekable();
+  if chunks.peek().is_none() {
+    stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+  }
+  while let Some(chunk) = chunks.next() {
+    let last = chunks.peek().is_none();
+    let length = chunk.len() as u16;
+    stream.push(last as u8);
+    stream.extend_from_slice(&length.to_le_bytes());
+    stream.extend_from_slice(&(!length).to_le_bytes());
+    stream.extend_from_slice(chunk);
+  }
+
+  stream.extend_from_slice(&adler32(data).to_be_bytes());
+  stream
+}
+
+/// The CRC-32 PNG chunks end with.
+pub struct Crc32 {
+  table: [u32; 256],
+  crc: u32,
+}
+
+impl Crc32 {
+  pub fn new() -> Self {
+    let mut table = [0; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+      let mut c = n as u32;
+      for _ in 0..8 {
+        c = if c & 1 == 1 { 0xedb8_8320 ^ (c >> 1) } else { c >> 1 };
+      }
+      *entry = c;
+    }
+    Crc32 { table, crc: 0xffff_ffff }
+  }
+
+  pub fn update(&mut self, bytes: &[u8]) {
+    for &byte in bytes {
+      self.crc = self.table[((self.crc ^ byte as u32) & 0xff) as usize] ^ (self.crc >> 8);
+    }
+  }
+
+  pub fn finish(&self) -> u32 {
+    self.crc ^ 0xffff_ffff
+  }
+}
+
+impl Default for Crc32 {
+  fn default() -> Self {
+    Crc32::new()
+  }
+}
+
+/// The Adler-32 checksum zlib streams end with.
+pub fn adler32(bytes: &[u8]) -> u32 {
+  const MOD: u32 = 65521;
+  // the largest run of bytes the sums can take without overflowing
+  const RUN: usize = 5552;
+
+  let (mut a, mut b) = (1u32, 0u32);
+  for run in bytes.chunks(RUN) {
+    for &byte in run {
+      a += byte as u32;
+      b += a;
+    }
+    a %= MOD;
+    b %= MOD;
+  }
+  (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finish()
+  }
+
+  #[test]
+  fn checksums_work() {
+    assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+    assert_eq!(0xae42_6082, crc32(b"IEND"));
+    assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    assert_eq!(1, adler32(b""));
+
+    // long enough to need reducing along the way
+    let long = vec![0xff; 100_000];
+    let (a, b) = long.iter().fold((1u64, 0u64), |(a, b), &byte| (a + byte as u64, b + a + byte as u64));
+    assert_eq!((((b % 65521) << 16) | (a % 65521)) as u32, adler32(&long));
+  }
+
+  #[test]
+  fn zlib_stored_splits_blocks() {
+    let data = vec![7; MAX_STORED + 10];
+    let stream = zlib_stored(&data);
+
+    assert_eq!(data.len() + 2 + 5 * 2 + 4, stream.len());
+    assert_eq!([0x78, 0x01, 0, 0xff, 0xff, 0, 0], stream[..7]);
+    let second = 2 + 5 + MAX_STORED;
+    assert_eq!([1, 10, 0, 0xf5, 0xff], stream[second..second + 5]);
+
+    assert_eq!(vec![0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1], zlib_stored(&[]));
+  }
+
+  #[test]
+  fn write_png_works() {
+    let mut image = Image::new(2, 1, [0, 0, 0]);
+    image.set(1, 0, [255, 0, 0]);
+    let mut png = Vec::new();
+    image.write_png(&mut png).unwrap();
+
+    assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+    assert_eq!(b"\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x01\x08\x02\0\0\0", &png[8..29]);
+    let ihdr_crc = crc32(&png[12..29]);
+    assert_eq!(ihdr_crc.to_be_bytes(), png[29..33]);
+
+    // a filter byte and two pixels, stored in a single block
+    let raw = [0, 0, 0, 0, 255, 0, 0];
+    assert_eq!(b"IDAT", &png[37..41]);
+    assert_eq!([0x78, 0x01, 1, 7, 0, 0xf8, 0xff], png[41..48]);
+    assert_eq!(raw, png[48..55]);
+    assert_eq!(adler32(&raw).to_be_bytes(), png[55..59]);
+    assert_eq!(b"\0\0\0\0IEND\xae\x42\x60\x82", &png[png.len() - 12..]);
+  }
+
+  #[test]
+  fn write_ppm_works() {
+    let mut image = Image::new(1, 2, [1, 2, 3]);
+    image.set(0, 1, [4, 5, 6]);
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    assert_eq!(b"P6\n1 2\n255\n\x01\x02\x03\x04\x05\x06".to_vec(), ppm);
+  }
+
+  #[test]
+  fn image_format_works() {
+    assert_eq!(Some(ImageFormat::Png), ImageFormat::from_file_name("map.PNG"));
+    assert_eq!(Some(ImageFormat::Ppm), ImageFormat::from_file_name("out/map.ppm"));
+    assert_eq!(None, ImageFormat::from_file_name("map.gif"));
+    assert_eq!(None, ImageFormat::from_file_name("png"));
+  }
+}
@@ -0,0 +1,121 @@
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+  /// `data/dayN`, looked up in the working directory and then in the crate.
+  Default,
+  Stdin,
+  Path(PathBuf),
+}
+
+impl Source {
+  /// Interprets a command line argument, treating `-` as stdin.
+  pub fn from_arg(arg: &str) -> Self {
+    if arg == "-" {
+      Source::Stdin
+    } else {
+      Source::Path(PathBuf::from(arg))
+    }
+  }
+}
+
+impl fmt::Display for Source {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Source::Default => write!(f, "default"),
+      Source::Stdin => write!(f, "stdin"),
+      Source::Path(ref path) => write!(f, "{}", path.display()),
+    }
+  }
+}
+
+/// Resolves a path relative to the working directory, falling back to the
+/// copy shipped with the crate when there is none.
+pub fn locate(relative: &Path) -> PathBuf {
+  if relative.is_absolute() || relative.exists() {
+    return relative.to_path_buf();
+  }
+
+  let bundled = Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+  if bundled.exists() {
+    bundled
+  } else {
+    relative.to_path_buf()
+  }
+}
+
+fn default_path(day: usize) -> PathBuf {
+  locate(&Path::new("data").join(format!("day{}", day)))
+}
+
+/// Opens a day's input for streaming rather than reading it all at once.
+pub fn open(day: usize, source: &Source) -> io::Result<Box<dyn Read>> {
+  match *source {
+    Source::Default => Ok(Box::new(File::open(default_path(day))?)),
+    Source::Path(ref path) => Ok(Box::new(File::open(path)?)),
+    Source::Stdin => Ok(Box::new(io::stdin())),
+  }
+}
+
+pub fn load(day: usize, source: &Source) -> io::Result<String> {
+  let mut raw = String::new();
+  open(day, source)?.read_to_string(&mut raw)?;
+  Ok(raw)
+}
+
+/// Splits a line on whitespace, pairing each token with its 1-based column.
+pub fn tokens(line: &str) -> Vec<(usize, &str)> {
+  let mut tokens = Vec::new();
+  let mut start = None;
+
+  for (i, ch) in line.char_indices() {
+    match (ch.is_whitespace(), start) {
+      (false, None) => start = Some(i),
+      (true, Some(from)) => {
+        tokens.push((line[..from].chars().count() + 1, &line[from..i]));
+        start = None;
+      },
+      _ => {}
+    }
+  }
+
+  if let Some(from) = start {
+    tokens.push((line[..from].chars().count() + 1, &line[from..]));
+  }
+  tokens
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn source_from_arg_works() {
+    assert_eq!(Source::Stdin, Source::from_arg("-"));
+    assert_eq!(Source::Path(PathBuf::from("data/day1")), Source::from_arg("data/day1"));
+  }
+
+  #[test]
+  fn tokens_works() {
+    assert_eq!(vec![(1, "turn"), (6, "on"), (10, "0,0")], tokens("turn on  0,0"));
+    assert_eq!(vec![(3, "toggle")], tokens("  toggle "));
+    assert!(tokens("   ").is_empty());
+  }
+
+  #[test]
+  fn load_default_works() {
+    let raw = load(4, &Source::Default).unwrap();
+    assert_eq!("ckczppom", raw.trim());
+  }
+
+  #[test]
+  fn load_missing_path_fails() {
+    let source = Source::Path(PathBuf::from("data/no-such-day"));
+    assert!(load(1, &source).is_err());
+  }
+}
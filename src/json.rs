@@ -0,0 +1,146 @@
+use std::fmt;
+
+/// The handful of JSON values the command line reports need.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Str(String),
+}
+
+impl From<bool> for Value {
+  fn from(value: bool) -> Self {
+    Value::Bool(value)
+  }
+}
+
+impl From<i64> for Value {
+  fn from(value: i64) -> Self {
+    Value::Int(value)
+  }
+}
+
+impl From<usize> for Value {
+  fn from(value: usize) -> Self {
+    Value::Int(value as i64)
+  }
+}
+
+impl From<f64> for Value {
+  fn from(value: f64) -> Self {
+    Value::Float(value)
+  }
+}
+
+impl<'a> From<&'a str> for Value {
+  fn from(value: &'a str) -> Self {
+    Value::Str(value.to_string())
+  }
+}
+
+impl From<String> for Value {
+  fn from(value: String) -> Self {
+    Value::Str(value)
+  }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    value.map_or(Value::Null, Into::into)
+  }
+}
+
+fn write_str(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+  write!(f, "\"")?;
+  for ch in s.chars() {
+    match ch {
+      '"' => write!(f, "\\\"")?,
+      '\\' => write!(f, "\\\\")?,
+      '\n' => write!(f, "\\n")?,
+      '\r' => write!(f, "\\r")?,
+      '\t' => write!(f, "\\t")?,
+      ch if (ch as u32) < 0x20 => write!(f, "\\u{:04x}", ch as u32)?,
+      ch => write!(f, "{}", ch)?,
+    }
+  }
+  write!(f, "\"")
+}
+
+impl fmt::Display for Value {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Value::Null => write!(f, "null"),
+      Value::Bool(value) => write!(f, "{}", value),
+      Value::Int(value) => write!(f, "{}", value),
+      // JSON has no representation for NaN or infinity
+      Value::Float(value) if !value.is_finite() => write!(f, "null"),
+      Value::Float(value) => write!(f, "{}", value),
+      Value::Str(ref value) => write_str(f, value),
+    }
+  }
+}
+
+/// A flat JSON object which keeps its fields in insertion order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Object {
+  fields: Vec<(&'static str, Value)>,
+}
+
+impl Object {
+  pub fn new() -> Self {
+    Object { fields: Vec::new() }
+  }
+
+  pub fn field<V: Into<Value>>(mut self, key: &'static str, value: V) -> Self {
+    self.fields.push((key, value.into()));
+    self
+  }
+
+  pub fn fields(&self) -> &[(&'static str, Value)] {
+    &self.fields
+  }
+}
+
+impl fmt::Display for Object {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{{")?;
+    for (i, &(key, ref value)) in self.fields.iter().enumerate() {
+      if i > 0 {
+        write!(f, ",")?;
+      }
+      write_str(f, key)?;
+      write!(f, ":{}", value)?;
+    }
+    write!(f, "}}")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn object_display_works() {
+    let object = Object::new()
+    .field("day", 1usize)
+    .field("answer", -3i64)
+    .field("units", None::<&str>)
+    .field("ok", true)
+    .field("label", "say \"hi\"\n");
+
+    assert_eq!(r#"{"day":1,"answer":-3,"units":null,"ok":true,"label":"say \"hi\"\n"}"#, object.to_string());
+  }
+
+  #[test]
+  fn floats_display_works() {
+    assert_eq!("12.5", Value::from(12.5).to_string());
+    assert_eq!("null", Value::from(f64::NAN).to_string());
+  }
+
+  #[test]
+  fn control_characters_are_escaped() {
+    assert_eq!(r#""a\u0001b""#, Value::from("a\u{1}b").to_string());
+  }
+}
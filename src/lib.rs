@@ -0,0 +1,27 @@
+//! Solutions to Advent of Code 2015.
+//!
+//! Each day lives in its own module and implements `solution::Solution`.
+//! The `registry` maps day numbers to those solutions so that callers can
+//! drive every day uniformly.
+
+extern crate crypto;
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod export;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod report;
+pub mod runner;
+pub mod solution;
+pub mod registry;
+
+pub mod day_one;
+pub mod day_two;
+pub mod day_three;
+pub mod day_four;
+pub mod day_five;
+pub mod day_six;
@@ -1,5 +1,21 @@
-mod day_six;
+extern crate adventofcode2015;
+
+use std::env;
+use std::io;
+use std::process;
+use adventofcode2015::{cli, report};
 
 fn main() {
-    day_six::main();
+  let command = match cli::parse(env::args().skip(1)) {
+    Ok(command) => command,
+    Err(msg) => {
+      eprintln!("{}\n\n{}", msg, cli::USAGE);
+      process::exit(2);
+    }
+  };
+
+  let (stdout, stderr) = (io::stdout(), io::stderr());
+  if !report::execute(&command, &mut stdout.lock(), &mut stderr.lock()) {
+    process::exit(1);
+  }
 }
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+use error::Result;
+use solution::{Answer, Solver};
+use day_one::DayOne;
+use day_two::DayTwo;
+use day_three::DayThree;
+use day_four::DayFour;
+use day_five::DayFive;
+use day_six::DaySix;
+
+pub struct Entry {
+  pub day: usize,
+  pub solver: &'static dyn Solver,
+}
+
+impl Entry {
+  pub fn solve(&self, raw: &str, part: usize) -> Result<Answer> {
+    let input = self.solver.prepare(raw)?;
+    self.solver.solve(&*input, part)
+  }
+}
+
+pub fn registry() -> BTreeMap<usize, Entry> {
+  let entries = vec![
+    Entry { day: 1, solver: &DayOne },
+    Entry { day: 2, solver: &DayTwo },
+    Entry { day: 3, solver: &DayThree },
+    Entry { day: 4, solver: &DayFour },
+    Entry { day: 5, solver: &DayFive },
+    Entry { day: 6, solver: &DaySix },
+  ];
+
+  entries.into_iter().map(|entry| (entry.day, entry)).collect()
+}
+
+pub fn get(day: usize) -> Option<Entry> {
+  registry().remove(&day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn registry_is_keyed_by_day() {
+    let days: Vec<usize> = registry().keys().cloned().collect();
+    assert_eq!(vec![1, 2, 3, 4, 5, 6], days);
+    assert!(get(0).is_none());
+  }
+
+  #[test]
+  fn entry_solves_its_day() {
+    assert_eq!(0, get(1).unwrap().solve("(())", 1).unwrap().value);
+    assert_eq!(11, get(3).unwrap().solve("^v^v^v^v^v", 2).unwrap().value);
+  }
+}
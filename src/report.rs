@@ -0,0 +1,549 @@
+//! Carries out a parsed `cli::Command`, writing what it reports to `out`
+//! and any failures to `err`.
+//!
+//! Every command returns whether it succeeded, so that the binary only has
+//! to turn that into an exit code.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use answers::{AnswerStore, Key, Verdict};
+use bench::{self, Baseline, Change};
+use cli::{Bench, Command, Days, Delivery, Format, Moves, Selection};
+use day_one;
+use day_three::{query, Address, Step, Symbols};
+use day_three::couriers::{self, Deliveries};
+use day_three::heatmap::Heatmap;
+use day_two::{self, report::BillOfMaterials};
+use day_two::cost::{self, Purchase, PaperRoll, RibbonRoll};
+use day_two::packing;
+use day_two::units::Measurer;
+use day_two::strategy::{self, GiftBag, OverlapMargin, PercentageSlack, SmallestSide};
+use error;
+use export::Export;
+use image::ImageFormat;
+use input::{self, Source};
+use json::Object;
+use registry;
+use runner::{self, solve_day, Solved};
+
+/// Runs `command`, returning whether it succeeded. Failing to write to
+/// `out` counts as a failure and is reported on `err`.
+pub fn execute<W: Write, E: Write>(command: &Command, out: &mut W, err: &mut E) -> bool {
+  let result = match *command {
+    Command::Run { ref selection, format, jobs } => run(out, err, selection, format, jobs),
+    Command::Verify { ref selection, ref answers, record } => verify(out, err, selection, answers, record),
+    Command::Bench(ref options) => run_bench(out, err, options),
+    Command::Floor { target, all_steps, ref input } => find_floor(out, err, target, all_steps, input),
+    Command::Trajectory { ref input, export } => print_trajectory(out, err, input, export),
+    Command::Materials { ref input, measurer: Some(ref measurer), .. } => print_measured(out, err, input, measurer),
+    Command::Materials { ref input, top, export, measurer: None } => print_materials(out, err, input, top, export),
+    Command::Cost { ref input, ref paper, ref ribbon } => print_cost(out, err, input, paper, ribbon),
+    Command::Strategies { ref input, slack_percent, margin, bag_ratio } => {
+      compare_strategies(out, err, input, slack_percent, margin, bag_ratio)
+    },
+    Command::Pack { ref input, crate_size, exact } => pack_presents(out, err, input, crate_size, exact),
+    Command::Couriers(ref delivery) => share_deliveries(out, err, delivery),
+    Command::Visits { ref delivery, at_least } => print_visits(out, err, delivery, at_least),
+    Command::Heatmap { ref delivery, ref output, format, scale, routes } => {
+      draw_heatmap(out, err, delivery, output, format, scale, routes)
+    },
+  };
+
+  result.unwrap_or_else(|failure| {
+    let _ = writeln!(err, "Could not write the output: {}", failure);
+    false
+  })
+}
+
+fn fail<E: Write>(err: &mut E, msg: &str) -> io::Result<bool> {
+  writeln!(err, "{}", msg)?;
+  Ok(false)
+}
+
+fn selected_days(days: &Days) -> Vec<usize> {
+  match *days {
+    Days::All => registry::registry().keys().cloned().collect(),
+    Days::One(day) => vec![day],
+  }
+}
+
+fn print_answers<W: Write>(out: &mut W, day: usize, solved: &Solved, selection: &Selection, format: Format) -> io::Result<()> {
+  for &(part, ref answer, elapsed) in &solved.answers {
+    match format {
+      Format::Text => writeln!(out, "Day {}, part {}: {}", day, part, answer)?,
+      Format::Json => writeln!(out, "{}", Object::new()
+        .field("day", day)
+        .field("part", part)
+        .field("label", answer.label)
+        .field("answer", answer.value)
+        .field("units", answer.units)
+        .field("elapsed_ns", elapsed.as_nanos() as i64)
+        .field("input", selection.input.to_string()))?,
+    }
+  }
+  Ok(())
+}
+
+fn print_summary<W: Write>(out: &mut W, results: &[(usize, Result<Solved, String>)], wall: Duration, jobs: usize, format: Format) -> io::Result<()> {
+  let failed = results.iter().filter(|&(_, result)| result.is_err()).count();
+
+  match format {
+    Format::Text => {
+      writeln!(out)?;
+      writeln!(out, "Day  Status  Time")?;
+      for &(day, ref result) in results {
+        match *result {
+          Ok(ref solved) => writeln!(out, "{:>3}  ok      {}", day, bench::format_duration(solved.elapsed))?,
+          Err(ref msg) => writeln!(out, "{:>3}  FAILED  {}", day, msg)?,
+        }
+      }
+      writeln!(out, "Total wall time {} on {} job(s), {} of {} day(s) failed",
+        bench::format_duration(wall), jobs, failed, results.len())
+    },
+    Format::Json => writeln!(out, "{}", Object::new()
+      .field("summary", true)
+      .field("days", results.len())
+      .field("failed", failed)
+      .field("jobs", jobs)
+      .field("wall_ns", wall.as_nanos() as i64)),
+  }
+}
+
+fn run<W: Write, E: Write>(out: &mut W, err: &mut E, selection: &Selection, format: Format, jobs: usize) -> io::Result<bool> {
+  let days = selected_days(&selection.days);
+
+  let start = Instant::now();
+  let results = runner::solve_days(&days, &selection.parts, &selection.input, jobs);
+  let wall = start.elapsed();
+
+  let mut ok = true;
+  for &(day, ref result) in &results {
+    match *result {
+      Ok(ref solved) => print_answers(out, day, solved, selection, format)?,
+      Err(ref msg) => {
+        writeln!(err, "{}", msg)?;
+        if format == Format::Json {
+          writeln!(out, "{}", Object::new().field("day", day).field("error", msg.as_str()))?;
+        }
+        ok = false;
+      }
+    }
+  }
+
+  if results.len() > 1 {
+    print_summary(out, &results, wall, jobs, format)?;
+  }
+  Ok(ok)
+}
+
+fn verify<W: Write, E: Write>(out: &mut W, err: &mut E, selection: &Selection, answers: &Path, record: bool) -> io::Result<bool> {
+  let path = input::locate(answers);
+  let mut store = match AnswerStore::load(&path) {
+    Ok(store) => store,
+    Err(failure) => return fail(err, &format!("{}: {}", path.display(), failure)),
+  };
+
+  let mut ok = true;
+  let mut recorded = 0;
+  for day in selected_days(&selection.days) {
+    let solved = match solve_day(day, &selection.parts, &selection.input) {
+      Ok(solved) => solved,
+      Err(msg) => {
+        writeln!(err, "{}", msg)?;
+        ok = false;
+        continue;
+      }
+    };
+
+    for (part, answer, _) in solved.answers {
+      let key = Key::new(day, part, &solved.raw);
+      match store.check(&key, answer.value) {
+        Verdict::Pass => writeln!(out, "PASS day {}, part {}: {}", day, part, answer.value)?,
+        Verdict::Fail { expected } => {
+          writeln!(out, "FAIL day {}, part {}: got {}, expected {}", day, part, answer.value, expected)?;
+          ok = false;
+        },
+        Verdict::New => {
+          writeln!(out, "NEW  day {}, part {}: {}", day, part, answer.value)?;
+          if record {
+            store.insert(key, answer.value);
+            recorded += 1;
+          }
+        },
+      }
+    }
+  }
+
+  if recorded > 0 {
+    if let Err(failure) = store.save(&path) {
+      return fail(err, &format!("{}: {}", path.display(), failure));
+    }
+    writeln!(out, "Recorded {} new answer(s) in {}", recorded, path.display())?;
+  }
+  Ok(ok)
+}
+
+fn run_bench<W: Write, E: Write>(out: &mut W, err: &mut E, options: &Bench) -> io::Result<bool> {
+  let selection = &options.selection;
+  let mut baseline = match Baseline::load(&options.baseline) {
+    Ok(baseline) => baseline,
+    Err(failure) => return fail(err, &format!("{}: {}", options.baseline.display(), failure)),
+  };
+
+  let mut ok = true;
+  for day in selected_days(&selection.days) {
+    let timings = registry::get(day)
+    .ok_or_else(|| format!("Unknown day: {}", day))
+    .and_then(|entry| {
+      let raw = input::load(day, &selection.input)
+      .map_err(|failure| format!("Day {}: could not read input from {}: {}", day, selection.input, failure))?;
+      bench::bench_day(&entry, &raw, &selection.parts, options.runs)
+      .map_err(|failure| format!("{}: {}", selection.input, failure))
+    });
+
+    let timings = match timings {
+      Ok(timings) => timings,
+      Err(msg) => {
+        writeln!(err, "{}", msg)?;
+        ok = false;
+        continue;
+      }
+    };
+
+    for (stage, stats) in timings {
+      let change = baseline.compare(day, stage, &stats, options.threshold);
+      if let Some(Change::Regressed(_)) = change {
+        ok = false;
+      }
+
+      match options.format {
+        Format::Text => {
+          let change = match change {
+            Some(Change::Regressed(delta)) => format!("  {:+.1}% REGRESSED", delta * 100.0),
+            Some(Change::Improved(delta)) => format!("  {:+.1}% improved", delta * 100.0),
+            Some(Change::Unchanged(delta)) => format!("  {:+.1}%", delta * 100.0),
+            None => String::new(),
+          };
+
+          writeln!(out, "day {} {:<6} min {:>10}  median {:>10}  max {:>10}{}",
+            day, stage.to_string(),
+            bench::format_duration(stats.min),
+            bench::format_duration(stats.median),
+            bench::format_duration(stats.max),
+            change)?;
+        },
+        Format::Json => {
+          let (change, regressed) = match change {
+            Some(Change::Regressed(delta)) => (Some(delta), true),
+            Some(Change::Improved(delta)) | Some(Change::Unchanged(delta)) => (Some(delta), false),
+            None => (None, false),
+          };
+
+          writeln!(out, "{}", Object::new()
+            .field("day", day)
+            .field("stage", stage.to_string())
+            .field("runs", stats.runs)
+            .field("min_ns", stats.min.as_nanos() as i64)
+            .field("median_ns", stats.median.as_nanos() as i64)
+            .field("max_ns", stats.max.as_nanos() as i64)
+            .field("change_pct", change.map(|delta| (delta * 1000.0).round() / 10.0))
+            .field("regressed", regressed)
+            .field("input", selection.input.to_string()))?;
+        },
+      }
+
+      if options.save {
+        baseline.record(day, stage, &stats);
+      }
+    }
+  }
+
+  if options.save {
+    if let Err(failure) = baseline.save(&options.baseline) {
+      return fail(err, &format!("{}: {}", options.baseline.display(), failure));
+    }
+    writeln!(out, "Saved baseline to {}", options.baseline.display())?;
+  }
+  Ok(ok)
+}
+
+fn find_floor<W: Write, E: Write>(out: &mut W, err: &mut E, target: i64, all_steps: bool, source: &Source) -> io::Result<bool> {
+  let reader = match input::open(1, source) {
+    Ok(reader) => reader,
+    Err(failure) => return fail(err, &format!("Day 1: could not read input from {}: {}", source, failure)),
+  };
+
+  if all_steps {
+    match day_one::steps_at(reader, target) {
+      Ok(ref steps) if steps.is_empty() => writeln!(out, "Floor {} is never reached", target)?,
+      Ok(steps) => {
+        let steps: Vec<String> = steps.iter().map(|step| step.to_string()).collect();
+        writeln!(out, "Floor {} is reached at steps {}", target, steps.join(", "))?;
+      },
+      Err(failure) => return fail(err, &format!("{}: {}", source, failure)),
+    }
+  } else {
+    match day_one::first_step_at(reader, target) {
+      Ok(Some(step)) => writeln!(out, "Floor {} is first reached at step {}", target, step)?,
+      Ok(None) => writeln!(out, "Floor {} is never reached", target)?,
+      Err(failure) => return fail(err, &format!("{}: {}", source, failure)),
+    }
+  }
+  Ok(true)
+}
+
+fn print_trajectory<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, export: Option<Export>) -> io::Result<bool> {
+  let reader = match input::open(1, source) {
+    Ok(reader) => reader,
+    Err(failure) => return fail(err, &format!("Day 1: could not read input from {}: {}", source, failure)),
+  };
+
+  let trajectory = match export {
+    Some(format) => {
+      return match day_one::export_trajectory(reader, BufWriter::new(&mut *out), format) {
+        Ok(()) => Ok(true),
+        Err(failure) => fail(err, &format!("{}: {}", source, failure)),
+      };
+    },
+    None => match day_one::trajectory(reader) {
+      Ok(trajectory) => trajectory,
+      Err(failure) => return fail(err, &format!("{}: {}", source, failure)),
+    },
+  };
+
+  let describe = |run: Option<day_one::Run>| match run {
+    Some(run) => format!("{} steps from step {}", run.len, run.start),
+    None => "never".to_string(),
+  };
+
+  writeln!(out, "Longest run above ground: {}", describe(trajectory.longest_above_ground()))?;
+  writeln!(out, "Longest run below ground: {}", describe(trajectory.longest_below_ground()))?;
+  writeln!(out)?;
+  writeln!(out, "Floor  Steps")?;
+  for (floor, steps) in trajectory.histogram() {
+    writeln!(out, "{:>5}  {}", floor, steps)?;
+  }
+  Ok(true)
+}
+
+// Reads the day 2 presents and hands them to `f`, describing any failure.
+fn with_presents<T, F>(source: &Source, f: F) -> Result<T, String>
+where F: FnOnce(&str) -> error::Result<T> {
+  let raw = input::load(2, source)
+  .map_err(|failure| format!("Day 2: could not read input from {}: {}", source, failure))?;
+  f(&raw).map_err(|failure| format!("{}: {}", source, failure))
+}
+
+fn print_materials<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, top: usize, export: Option<Export>) -> io::Result<bool> {
+  let bill = match with_presents(source, |raw| day_two::parse(raw).and_then(|presents| BillOfMaterials::new(&presents))) {
+    Ok(bill) => bill,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  if let Some(format) = export {
+    return match bill.export(BufWriter::new(&mut *out), format) {
+      Ok(()) => Ok(true),
+      Err(failure) => fail(err, &failure.to_string()),
+    };
+  }
+
+  let totals = &bill.totals;
+  writeln!(out, "Presents:  {}", bill.presents.len())?;
+  writeln!(out, "Paper:     {} square feet, {} of it slack", totals.paper + totals.slack, totals.slack)?;
+  writeln!(out, "Ribbon:    {} feet, {} for wrapping and {} for bows",
+    totals.wrap_ribbon + totals.bow_ribbon, totals.wrap_ribbon, totals.bow_ribbon)?;
+
+  if top > 0 {
+    writeln!(out)?;
+    writeln!(out, "Present  Dimensions   Paper  Slack  Wrap ribbon  Bow ribbon")?;
+    for materials in bill.most_expensive(top) {
+      let (l, w, h) = materials.dimensions;
+      writeln!(out, "{:>7}  {:<11}  {:>5}  {:>5}  {:>11}  {:>10}",
+        materials.number, format!("{}x{}x{}", l, w, h),
+        materials.paper, materials.slack, materials.wrap_ribbon, materials.bow_ribbon)?;
+    }
+  }
+  Ok(true)
+}
+
+fn print_measured<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, measurer: &Measurer) -> io::Result<bool> {
+  let presents = match with_presents(source, day_two::parse_fractional) {
+    Ok(presents) => presents,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  write_measured(out, &presents, measurer)?;
+  Ok(true)
+}
+
+fn write_measured<W: Write>(out: &mut W, presents: &[(f64, f64, f64)], measurer: &Measurer) -> io::Result<()> {
+  let show = |value| measurer.rounding.format(value);
+  writeln!(out, "Presents:  {}", presents.len())?;
+  writeln!(out, "Paper:     {} square {}", show(measurer.total_area(presents)), measurer.output)?;
+  writeln!(out, "Ribbon:    {} {}", show(measurer.total_ribbon(presents)), measurer.output)
+}
+
+fn print_cost<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, paper: &PaperRoll, ribbon: &RibbonRoll) -> io::Result<bool> {
+  let estimate = match with_presents(source, |raw| day_two::parse(raw).and_then(|presents| cost::estimate(&presents, paper, ribbon))) {
+    Ok(estimate) => estimate,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  let describe = |purchase: &Purchase, price: u64| {
+    format!("{} roll(s) at {} = {} (the totals alone suggest {}, {} feet of roll left over)",
+      purchase.rolls, cost::format_price(price), cost::format_price(purchase.cost),
+      purchase.minimum_rolls, purchase.waste)
+  };
+  writeln!(out, "Paper:   {}", describe(&estimate.paper, paper.price))?;
+  writeln!(out, "Ribbon:  {}", describe(&estimate.ribbon, ribbon.price))?;
+  writeln!(out, "Total:   {}", cost::format_price(estimate.cost))?;
+  Ok(true)
+}
+
+fn compare_strategies<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, slack_percent: usize, margin: usize, bag_ratio: usize) -> io::Result<bool> {
+  let percentage = PercentageSlack { percent: slack_percent };
+  let overlap = OverlapMargin { margin };
+  let bag = GiftBag { ratio: bag_ratio, paper: SmallestSide };
+
+  let results = with_presents(source, |raw| {
+    day_two::parse(raw)
+    .and_then(|presents| strategy::compare(&presents, &[&SmallestSide, &percentage, &overlap, &bag]))
+  });
+  let results = match results {
+    Ok(results) => results,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  writeln!(out, "{:<40}  {:>12}  {:>12}  {:>5}", "Strategy", "Paper", "Ribbon", "Bags")?;
+  for (name, totals) in results {
+    writeln!(out, "{:<40}  {:>12}  {:>12}  {:>5}", name, totals.paper, totals.ribbon, totals.bags)?;
+  }
+  Ok(true)
+}
+
+fn pack_presents<W: Write, E: Write>(out: &mut W, err: &mut E, source: &Source, crate_size: (usize, usize, usize), exact: bool) -> io::Result<bool> {
+  let packing = with_presents(source, |raw| {
+    day_two::parse(raw)
+    .and_then(|presents| {
+      if exact {
+        packing::pack_exact(&presents, crate_size)
+      } else {
+        packing::pack(&presents, crate_size)
+      }
+    })
+  });
+
+  match packing {
+    Ok(packing) => {
+      let (l, w, h) = crate_size;
+      writeln!(out, "{} crate(s) of {}x{}x{}, {:.1}% full", packing.crate_count(), l, w, h, packing.fill_ratio() * 100.0)?;
+      Ok(true)
+    },
+    Err(msg) => fail(err, &msg),
+  }
+}
+
+// Reads the day 3 directions and sends the couriers out, returning what
+// they delivered along with each courier's route.
+fn deliver(delivery: &Delivery) -> Result<(Deliveries, Vec<Vec<Address>>), String> {
+  fn follow<D: Step>(raw: &str, symbols: &Symbols<D>, delivery: &Delivery) -> error::Result<(Deliveries, Vec<Vec<Address>>)> {
+    let split = delivery.dispatch.split(&symbols.streams(raw)?)?;
+    let routes = split.iter().map(|directions| delivery.town.route(directions)).collect::<error::Result<_>>()?;
+    Ok((couriers::deliver(&split, &delivery.town)?, routes))
+  }
+
+  let source = &delivery.input;
+  input::load(3, source)
+  .map_err(|failure| format!("Day 3: could not read input from {}: {}", source, failure))
+  .and_then(|raw| {
+    match delivery.moves {
+      Moves::Four(ref symbols) => follow(&raw, symbols, delivery),
+      Moves::Eight(ref symbols) => follow(&raw, symbols, delivery),
+      Moves::Hex(ref symbols) => follow(&raw, symbols, delivery),
+    }
+    .map_err(|failure| format!("{}: {}", source, failure))
+  })
+}
+
+fn share_deliveries<W: Write, E: Write>(out: &mut W, err: &mut E, delivery: &Delivery) -> io::Result<bool> {
+  let deliveries = match deliver(delivery) {
+    Ok((deliveries, _)) => deliveries,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  for (i, presents) in deliveries.couriers.iter().enumerate() {
+    writeln!(out, "Courier {}: {} houses", i + 1, presents.len())?;
+  }
+  writeln!(out, "Together: {} houses", deliveries.combined.len())?;
+  Ok(true)
+}
+
+fn print_visits<W: Write, E: Write>(out: &mut W, err: &mut E, delivery: &Delivery, at_least: Option<usize>) -> io::Result<bool> {
+  let presents = match deliver(delivery) {
+    Ok((deliveries, _)) => deliveries.combined,
+    Err(msg) => return fail(err, &msg),
+  };
+
+  if let Some(((x, y), count)) = query::busiest(&presents) {
+    writeln!(out, "Busiest house: ({}, {}) with {} presents", x, y, count)?;
+  }
+  if let Some(k) = at_least {
+    writeln!(out, "{} houses got at least {} presents", query::at_least(&presents, k), k)?;
+  }
+  writeln!(out)?;
+  writeln!(out, "Presents  Houses")?;
+  for (count, houses) in query::histogram(&presents) {
+    writeln!(out, "{:>8}  {:>6}", count, houses)?;
+  }
+  Ok(true)
+}
+
+fn draw_heatmap<W: Write, E: Write>(
+  out: &mut W,
+  err: &mut E,
+  delivery: &Delivery,
+  output: &Path,
+  format: ImageFormat,
+  scale: usize,
+  show_routes: bool,
+) -> io::Result<bool> {
+  let image = deliver(delivery).and_then(|(deliveries, routes)| {
+    let routes = if show_routes { &routes[..] } else { &[] };
+    routes.iter()
+    .fold(Heatmap::new(&deliveries.combined).with_scale(scale), |heatmap, route| heatmap.with_route(route))
+    .render()
+    .map_err(|failure| format!("{}: {}", delivery.input, failure))
+  });
+
+  let written = image.and_then(|image| {
+    File::create(output)
+    .and_then(|file| image.write(BufWriter::new(file), format))
+    .map(|_| image)
+    .map_err(|failure| format!("Could not write {}: {}", output.display(), failure))
+  });
+
+  match written {
+    Ok(image) => {
+      writeln!(out, "Wrote a {}x{} map to {}", image.width, image.height, output.display())?;
+      Ok(true)
+    },
+    Err(msg) => fail(err, &msg),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use day_two::units::{Rounding, Unit};
+
+  #[test]
+  fn measured_totals_are_tidy() {
+    let presents = day_two::parse_fractional("2.5x3x4\n0.5x1x1").unwrap();
+    let mut out = Vec::new();
+    write_measured(&mut out, &presents, &Measurer::new(Unit::Feet, Unit::Centimetres, Rounding::Exact)).unwrap();
+    // 71 square feet of paper and 44.5 feet of ribbon
+    assert_eq!("Presents:  2\nPaper:     65961.16 square cm\nRibbon:    1356.36 cm\n", String::from_utf8(out).unwrap());
+  }
+}
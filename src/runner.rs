@@ -0,0 +1,89 @@
+use std::panic;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use input::{self, Source};
+use registry;
+use solution::Answer;
+
+pub struct Solved {
+  pub raw: String,
+  pub answers: Vec<(usize, Answer, Duration)>,
+  pub elapsed: Duration,
+}
+
+pub fn solve_day(day: usize, parts: &[usize], source: &Source) -> Result<Solved, String> {
+  let start = Instant::now();
+  let entry = registry::get(day).ok_or_else(|| format!("Unknown day: {}", day))?;
+  let raw = input::load(day, source)
+  .map_err(|err| format!("Day {}: could not read input from {}: {}", day, source, err))?;
+
+  let input = entry.solver.prepare(&raw)
+  .map_err(|err| format!("{}: {}", source, err))?;
+
+  let mut answers = Vec::with_capacity(parts.len());
+  for &part in parts {
+    let part_start = Instant::now();
+    let answer = panic::catch_unwind(panic::AssertUnwindSafe(|| entry.solver.solve(&*input, part)))
+    .map_err(|_| format!("Day {}, part {} failed", day, part))?
+    .map_err(|err| err.to_string())?;
+    answers.push((part, answer, part_start.elapsed()));
+  }
+  Ok(Solved { raw, answers, elapsed: start.elapsed() })
+}
+
+/// Solves `days` on up to `jobs` threads, returning the results in the
+/// order the days were given.
+pub fn solve_days(days: &[usize], parts: &[usize], source: &Source, jobs: usize) -> Vec<(usize, Result<Solved, String>)> {
+  let next = AtomicUsize::new(0);
+  let results = Mutex::new(Vec::with_capacity(days.len()));
+
+  thread::scope(|scope| {
+    for _ in 0..jobs.max(1).min(days.len()) {
+      scope.spawn(|| {
+        loop {
+          let i = next.fetch_add(1, Ordering::SeqCst);
+          let day = match days.get(i) {
+            Some(&day) => day,
+            None => break,
+          };
+
+          let result = solve_day(day, parts, source);
+          results.lock().unwrap().push((i, day, result));
+        }
+      });
+    }
+  });
+
+  let mut results = results.into_inner().unwrap();
+  results.sort_by_key(|&(i, _, _)| i);
+  results.into_iter().map(|(_, day, result)| (day, result)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::path::PathBuf;
+
+  #[test]
+  fn solve_days_keeps_day_order() {
+    let results = solve_days(&[5, 1, 2, 3], &[1], &Source::Default, 3);
+    let days: Vec<usize> = results.iter().map(|&(day, _)| day).collect();
+    assert_eq!(vec![5, 1, 2, 3], days);
+
+    let answers: Vec<i64> = results.iter()
+    .map(|(_, result)| result.as_ref().unwrap().answers[0].1.value)
+    .collect();
+    assert_eq!(vec![258, 138, 1588178, 2081], answers);
+  }
+
+  #[test]
+  fn solve_days_reports_failures() {
+    let missing = Source::Path(PathBuf::from("data/no-such-day"));
+    let results = solve_days(&[1, 42], &[1], &missing, 2);
+
+    assert!(results[0].1.is_err());
+    assert_eq!(Some("Unknown day: 42".to_string()), results[1].1.as_ref().err().cloned());
+  }
+}
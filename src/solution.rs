@@ -0,0 +1,65 @@
+use std::any::Any;
+use std::fmt;
+use error::Result;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+  pub label: &'static str,
+  pub value: i64,
+  pub units: Option<&'static str>,
+}
+
+impl Answer {
+  pub fn new(label: &'static str, value: i64) -> Self {
+    Answer { label, value, units: None }
+  }
+
+  pub fn with_units(mut self, units: &'static str) -> Self {
+    self.units = Some(units);
+    self
+  }
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: {}", self.label, self.value)?;
+    if let Some(units) = self.units {
+      write!(f, " {}", units)?;
+    }
+    Ok(())
+  }
+}
+
+pub trait Solution {
+  type Input;
+
+  fn parse(&self, raw: &str) -> Result<Self::Input>;
+  fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+  fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+// Type-erased view of a `Solution`, so that days with different input
+// types can live side by side in the registry.
+pub trait Solver: Sync {
+  fn prepare(&self, raw: &str) -> Result<Box<dyn Any>>;
+  fn solve(&self, input: &dyn Any, part: usize) -> Result<Answer>;
+}
+
+impl<S> Solver for S
+where S: Solution + Sync, S::Input: 'static {
+  fn prepare(&self, raw: &str) -> Result<Box<dyn Any>> {
+    let input = self.parse(raw)?;
+    Ok(Box::new(input))
+  }
+
+  fn solve(&self, input: &dyn Any, part: usize) -> Result<Answer> {
+    let input = input.downcast_ref::<S::Input>()
+    .expect("Input was prepared by a different solution");
+
+    match part {
+      1 => self.part_one(input),
+      2 => self.part_two(input),
+      _ => panic!("Unknown part: {}", part),
+    }
+  }
+}